@@ -0,0 +1,352 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+use std::num::ParseIntError;
+
+use anyhow::Result;
+use input_parser::{ParsedLines, StreamError};
+use solution::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Sweep;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(sweep(input_parser::parse_lines(input.as_bytes()))?)
+    }
+
+    fn part1(sweep: &Self::Input) -> Result<u32> {
+        Ok(sweep.trend(1).increases)
+    }
+
+    fn part2(sweep: &Self::Input) -> Result<u32> {
+        Ok(sweep.trend(3).increases)
+    }
+
+    fn input_stats(sweep: &Self::Input) -> Vec<(&'static str, i64)> {
+        let trend = sweep.trend(1);
+        let mut stats = vec![
+            ("depths", sweep.depths as i64),
+            ("decreases", trend.decreases as i64),
+            ("plateaus", trend.plateaus as i64),
+            ("window 2 increases", sweep.trend(2).increases as i64),
+            ("window 5 increases", sweep.trend(5).increases as i64),
+        ];
+        if let Some(run) = trend.longest_increase {
+            stats.push(("longest increasing run", run.len as i64));
+            stats.push(("longest run from depth", run.from));
+            stats.push(("longest run to depth", run.to));
+        }
+        stats
+    }
+}
+
+/// Window sizes a sweep compares, 1 stands for the single depths and 3 is the puzzle's window.
+pub const WINDOW_SIZES: [usize; 4] = [1, 2, 3, 5];
+
+/// Trends of a sonar sweep, gathered while the depths are read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sweep {
+    /// Number of measurements
+    pub depths: usize,
+    /// Trend of the window sums for each of [`WINDOW_SIZES`]
+    pub trends: Vec<(usize, Trend)>,
+}
+
+impl Sweep {
+    /// Trend of the sums of `window_size` consecutive depths, which must be in [`WINDOW_SIZES`].
+    pub fn trend(&self, window_size: usize) -> Trend {
+        self.trends
+            .iter()
+            .find(|&&(size, _)| size == window_size)
+            .map(|&(_, trend)| trend)
+            .expect("window size is not compared")
+    }
+}
+
+/// Analyzes the depths as they are parsed without collecting them, stopping at the first line
+/// that cannot be read or parsed.
+pub fn sweep<R: BufRead>(lines: ParsedLines<i32, R>) -> Result<Sweep, StreamError<ParseIntError>> {
+    let mut depths = 0;
+    let mut error = None;
+    let valid = lines.map_while(|line| match line {
+        Ok(depth) => {
+            depths += 1;
+            Some(depth as i64)
+        }
+        Err(e) => {
+            error = Some(e);
+            None
+        }
+    });
+    let trends = compare_windows(valid, &WINDOW_SIZES);
+    match error {
+        Some(e) => Err(e),
+        None => Ok(Sweep { depths, trends }),
+    }
+}
+
+/// Iterator over the sums of all windows of consecutive depths.
+///
+/// Each sum is derived from the previous one by adding the depth entering the window and
+/// subtracting the one leaving it, so the iterator takes O(1) per item for any window size.
+pub struct SlidingSums<I> {
+    depths: I,
+    window: VecDeque<i64>,
+    window_size: usize,
+    sum: i64,
+}
+
+impl<I: Iterator<Item = i64>> Iterator for SlidingSums<I> {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        loop {
+            let depth = self.depths.next()?;
+            self.window.push_back(depth);
+            self.sum += depth;
+            if self.window.len() > self.window_size {
+                self.sum -= self.window.pop_front().unwrap();
+            }
+            if self.window.len() == self.window_size {
+                return Some(self.sum);
+            }
+        }
+    }
+}
+
+/// Sums of all windows of `window_size` consecutive depths, in order.
+pub fn sliding_sums<I: IntoIterator<Item = i64>>(
+    depths: I,
+    window_size: usize,
+) -> SlidingSums<I::IntoIter> {
+    assert!(window_size > 0, "window size must be positive");
+    SlidingSums {
+        depths: depths.into_iter(),
+        window: VecDeque::with_capacity(window_size + 1),
+        window_size,
+        sum: 0,
+    }
+}
+
+/// Consecutive measurements in which every depth is greater than the previous one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IncreasingRun {
+    /// Index of the first measurement of the run
+    pub start: usize,
+    /// Number of measurements in the run
+    pub len: usize,
+    /// Depth at the start of the run
+    pub from: i64,
+    /// Depth at the end of the run
+    pub to: i64,
+}
+
+/// How the depth changes between consecutive measurements.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Trend {
+    pub increases: u32,
+    pub decreases: u32,
+    /// Measurements with the same depth as the previous one
+    pub plateaus: u32,
+    /// The first of the longest increasing runs, `None` if there are no measurements
+    pub longest_increase: Option<IncreasingRun>,
+}
+
+/// Builds a [`Trend`] from measurements that are fed one at a time.
+#[derive(Clone, Debug, Default)]
+pub struct TrendAnalyzer {
+    trend: Trend,
+    measurements: usize,
+    previous: Option<i64>,
+    current_run: Option<IncreasingRun>,
+}
+
+impl TrendAnalyzer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, depth: i64) {
+        let index = self.measurements;
+        self.measurements += 1;
+        let run = match (self.previous, self.current_run) {
+            (Some(previous), Some(run)) if previous < depth => {
+                self.trend.increases += 1;
+                IncreasingRun {
+                    len: run.len + 1,
+                    to: depth,
+                    ..run
+                }
+            }
+            (previous, _) => {
+                match previous {
+                    Some(previous) if previous > depth => self.trend.decreases += 1,
+                    Some(_) => self.trend.plateaus += 1,
+                    None => {}
+                }
+                IncreasingRun {
+                    start: index,
+                    len: 1,
+                    from: depth,
+                    to: depth,
+                }
+            }
+        };
+        let longer = self
+            .trend
+            .longest_increase
+            .map(|longest| longest.len < run.len);
+        if longer.unwrap_or(true) {
+            self.trend.longest_increase = Some(run);
+        }
+        self.current_run = Some(run);
+        self.previous = Some(depth);
+    }
+
+    pub fn trend(&self) -> Trend {
+        self.trend
+    }
+}
+
+/// Trend of a sequence of depths.
+pub fn analyze<I: IntoIterator<Item = i64>>(depths: I) -> Trend {
+    let mut analyzer = TrendAnalyzer::new();
+    for depth in depths {
+        analyzer.push(depth);
+    }
+    analyzer.trend()
+}
+
+/// Trends of the sliding window sums for each of `window_sizes`, computed in a single pass.
+pub fn compare_windows<I: IntoIterator<Item = i64>>(
+    depths: I,
+    window_sizes: &[usize],
+) -> Vec<(usize, Trend)> {
+    assert!(
+        window_sizes.iter().all(|&size| size > 0),
+        "window sizes must be positive"
+    );
+    let max_size = window_sizes.iter().copied().max().unwrap_or(0);
+    // The last `max_size` depths, from which each window drops its oldest depth
+    let mut recent: VecDeque<i64> = VecDeque::with_capacity(max_size + 1);
+    let mut sums = vec![0; window_sizes.len()];
+    let mut analyzers = vec![TrendAnalyzer::new(); window_sizes.len()];
+    for depth in depths {
+        recent.push_front(depth);
+        if recent.len() > max_size + 1 {
+            recent.pop_back();
+        }
+        let windows = window_sizes.iter().zip(&mut sums).zip(&mut analyzers);
+        for ((&size, sum), analyzer) in windows {
+            *sum += depth;
+            if let Some(leaving) = recent.get(size) {
+                *sum -= leaving;
+            }
+            if recent.len() >= size {
+                analyzer.push(*sum);
+            }
+        }
+    }
+    window_sizes
+        .iter()
+        .copied()
+        .zip(analyzers.iter().map(TrendAnalyzer::trend))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    fn example() {
+        let sweep = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part1(&sweep).unwrap(), 7);
+        assert_eq!(Day01::part2(&sweep).unwrap(), 5);
+        assert_eq!(sweep.depths, 10);
+    }
+
+    #[test]
+    fn empty_input() {
+        let sweep = Day01::parse("").unwrap();
+        assert_eq!(sweep.depths, 0);
+        assert_eq!(sweep.trend(1), Trend::default());
+        assert_eq!(analyze(Vec::new()), Trend::default());
+        assert_eq!(
+            compare_windows(Vec::new(), &[1, 3]),
+            [(1, Trend::default()), (3, Trend::default())]
+        );
+    }
+
+    #[test]
+    fn window_longer_than_input() {
+        assert_eq!(sliding_sums(vec![1, 2], 3).count(), 0);
+        assert_eq!(compare_windows(vec![1, 2], &[3]), [(3, Trend::default())]);
+    }
+
+    #[test]
+    fn plateaus() {
+        let trend = analyze(vec![1, 1, 2, 2, 3]);
+        assert_eq!(
+            (trend.increases, trend.decreases, trend.plateaus),
+            (2, 0, 2)
+        );
+    }
+
+    #[test]
+    fn first_of_tied_longest_runs() {
+        let trend = analyze(vec![1, 2, 0, 5, 5, 6]);
+        let expected = IncreasingRun {
+            start: 0,
+            len: 2,
+            from: 1,
+            to: 2,
+        };
+        assert_eq!(trend.longest_increase, Some(expected));
+    }
+
+    #[test]
+    fn longest_run_depth_range() {
+        let trend = analyze(vec![5, 3, 4, 8, 9, 2]);
+        let expected = IncreasingRun {
+            start: 1,
+            len: 4,
+            from: 3,
+            to: 9,
+        };
+        assert_eq!(trend.longest_increase, Some(expected));
+    }
+
+    #[test]
+    fn compare_windows_matches_sliding_sums() {
+        let example = Day01::parse(EXAMPLE).unwrap();
+        let depths: Vec<i64> = EXAMPLE.lines().map(|l| l.parse().unwrap()).collect();
+        for (size, trend) in compare_windows(depths.clone(), &[1, 3, 5, 12]) {
+            assert_eq!(
+                trend,
+                analyze(sliding_sums(depths.clone(), size)),
+                "window {}",
+                size
+            );
+        }
+        assert_eq!(example.trend(3), analyze(sliding_sums(depths, 3)));
+    }
+
+    #[test]
+    fn sweep_stops_at_first_error() {
+        let lines = input_parser::parse_lines("1\n2\nx\n3\ny\n".as_bytes());
+        match sweep(lines) {
+            Err(StreamError::Parse(e)) => assert_eq!(e.line, 3),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,526 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use solution::Solution;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+/// A command of a script together with the line it was written on.
+///
+/// Commands expanded from a repeat block keep the line of their place in the block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Command {
+    /// 1-based line of the script
+    pub line: usize,
+    pub direction: Direction,
+}
+
+/// Upper bound on the number of commands after expanding all repeat blocks
+const MAX_COMMANDS: usize = 10_000_000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseDirectionError {
+    /// 1-based line of the script on which the error was detected
+    pub line: usize,
+    pub kind: ParseDirectionErrorKind,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseDirectionErrorKind {
+    /// No command at all where one was expected
+    Empty,
+    UnknownCommand(String),
+    /// Input after a single command
+    Trailing(String),
+    /// A direction or `repeat` without a number on the same line
+    MissingNumber(String),
+    InvalidNumber(String, ParseIntError),
+    /// `repeat <n>` that is not followed by `{`
+    MissingBlock,
+    /// A block opened on the error line that is never closed
+    UnclosedBlock,
+    UnmatchedBrace,
+    TooManyCommands,
+}
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ParseDirectionErrorKind::Empty => write!(f, "expected a command"),
+            ParseDirectionErrorKind::UnknownCommand(c) => write!(f, "unknown command {:?}", c),
+            ParseDirectionErrorKind::Trailing(t) => {
+                write!(f, "unexpected {:?} after the command", t)
+            }
+            ParseDirectionErrorKind::MissingNumber(c) => write!(f, "{} needs a number", c),
+            ParseDirectionErrorKind::InvalidNumber(n, e) => {
+                write!(f, "invalid number {:?}: {}", n, e)
+            }
+            ParseDirectionErrorKind::MissingBlock => {
+                write!(f, "expected \"{{\" after repeat count")
+            }
+            ParseDirectionErrorKind::UnclosedBlock => write!(f, "block is never closed"),
+            ParseDirectionErrorKind::UnmatchedBrace => write!(f, "\"}}\" without matching \"{{\""),
+            ParseDirectionErrorKind::TooManyCommands => {
+                write!(f, "script expands to more than {} commands", MAX_COMMANDS)
+            }
+        }
+    }
+}
+
+impl Error for ParseDirectionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParseDirectionErrorKind::InvalidNumber(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+struct Token<'a> {
+    text: &'a str,
+    line: usize,
+}
+
+/// Parser for command scripts, which are made of directions and nested repeat blocks.
+///
+/// ```text
+/// # comments run to the end of the line
+/// forward 5
+/// repeat 3 { forward 2 down 1 }
+/// ```
+struct ScriptParser<'a> {
+    tokens: Vec<Token<'a>>,
+    position: usize,
+}
+
+impl<'a> ScriptParser<'a> {
+    fn new(script: &'a str) -> Self {
+        let mut tokens = Vec::new();
+        for (idx, line) in script.lines().enumerate() {
+            let code = line.split('#').next().unwrap_or("");
+            // Braces need no surrounding whitespace
+            for word in code.split_whitespace() {
+                for (start, part) in split_braces(word) {
+                    tokens.push(Token {
+                        text: &word[start..start + part.len()],
+                        line: idx + 1,
+                    });
+                }
+            }
+        }
+        ScriptParser {
+            tokens,
+            position: 0,
+        }
+    }
+
+    fn error(line: usize, kind: ParseDirectionErrorKind) -> ParseDirectionError {
+        ParseDirectionError { line, kind }
+    }
+
+    fn next(&mut self) -> Option<&Token<'a>> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        token
+    }
+
+    /// Parses the number after the command on line `line`.
+    fn number<T: FromStr<Err = ParseIntError>>(
+        &mut self,
+        command: &str,
+        line: usize,
+    ) -> Result<T, ParseDirectionError> {
+        match self.tokens.get(self.position) {
+            Some(token) if token.line == line && token.text != "{" && token.text != "}" => {
+                self.position += 1;
+                token.text.parse().map_err(|e| {
+                    Self::error(
+                        line,
+                        ParseDirectionErrorKind::InvalidNumber(String::from(token.text), e),
+                    )
+                })
+            }
+            _ => Err(Self::error(
+                line,
+                ParseDirectionErrorKind::MissingNumber(String::from(command)),
+            )),
+        }
+    }
+
+    /// Parses the stride of the direction named `text`, `None` if `text` names no direction.
+    fn direction(
+        &mut self,
+        text: &str,
+        line: usize,
+    ) -> Option<Result<Direction, ParseDirectionError>> {
+        let direction: fn(i32) -> Direction = match text {
+            "forward" => Direction::Forward,
+            "down" => Direction::Down,
+            "up" => Direction::Up,
+            _ => return None,
+        };
+        Some(self.number(text, line).map(direction))
+    }
+
+    /// Parses commands up to the end of the block opened on line `opened_at`, or of the script.
+    fn block(&mut self, opened_at: Option<usize>) -> Result<Vec<Command>, ParseDirectionError> {
+        let mut commands = Vec::new();
+        loop {
+            let (text, line) = match self.next() {
+                Some(token) => (token.text, token.line),
+                None => {
+                    return match opened_at {
+                        Some(line) => {
+                            Err(Self::error(line, ParseDirectionErrorKind::UnclosedBlock))
+                        }
+                        None => Ok(commands),
+                    }
+                }
+            };
+            match text {
+                "}" if opened_at.is_some() => return Ok(commands),
+                "}" => return Err(Self::error(line, ParseDirectionErrorKind::UnmatchedBrace)),
+                "repeat" => {
+                    let count: usize = self.number(text, line)?;
+                    match self.next() {
+                        Some(token) if token.text == "{" => {}
+                        _ => return Err(Self::error(line, ParseDirectionErrorKind::MissingBlock)),
+                    }
+                    let body = self.block(Some(line))?;
+                    let expanded = body
+                        .len()
+                        .checked_mul(count)
+                        .and_then(|n| n.checked_add(commands.len()));
+                    if expanded.map(|n| n > MAX_COMMANDS).unwrap_or(true) {
+                        return Err(Self::error(line, ParseDirectionErrorKind::TooManyCommands));
+                    }
+                    // Bounded by the check above even if `count` is huge and the body empty
+                    commands.extend(body.iter().copied().cycle().take(body.len() * count));
+                }
+                c => match self.direction(c, line) {
+                    Some(direction) => commands.push(Command {
+                        line,
+                        direction: direction?,
+                    }),
+                    None => {
+                        let kind = ParseDirectionErrorKind::UnknownCommand(String::from(c));
+                        return Err(Self::error(line, kind));
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Splits `{` and `}` off the rest of a word, with the byte offset of every part.
+fn split_braces(word: &str) -> Vec<(usize, &str)> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for (idx, c) in word.char_indices() {
+        if c == '{' || c == '}' {
+            if start < idx {
+                parts.push((start, &word[start..idx]));
+            }
+            parts.push((idx, &word[idx..idx + 1]));
+            start = idx + 1;
+        }
+    }
+    if start < word.len() {
+        parts.push((start, &word[start..]));
+    }
+    parts
+}
+
+/// Parses a command script into the commands it stands for, with all repeat blocks expanded.
+pub fn parse_script(script: &str) -> Result<Vec<Command>, ParseDirectionError> {
+    ScriptParser::new(script).block(None)
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    /// Parses a single command such as `forward 5`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = ScriptParser::new(s);
+        let (text, line) = match parser.next() {
+            Some(token) => (token.text, token.line),
+            None => return Err(ScriptParser::error(1, ParseDirectionErrorKind::Empty)),
+        };
+        let direction = parser.direction(text, line).unwrap_or_else(|| {
+            Err(ScriptParser::error(
+                line,
+                ParseDirectionErrorKind::UnknownCommand(String::from(text)),
+            ))
+        })?;
+        match parser.next() {
+            Some(token) => {
+                let kind = ParseDirectionErrorKind::Trailing(String::from(token.text));
+                Err(ScriptParser::error(token.line, kind))
+            }
+            None => Ok(direction),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Direction::Forward(stride) => write!(f, "forward {}", stride),
+            Direction::Down(stride) => write!(f, "down {}", stride),
+            Direction::Up(stride) => write!(f, "up {}", stride),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Position {
+    pub horizontal: i32,
+    pub depth: i32,
+    pub aim: i32,
+}
+
+/// Decides how a command changes the position of the submarine.
+pub trait SteeringModel {
+    /// Returns the position after the command, or `None` if a coordinate would overflow.
+    fn steer(&self, position: &Position, direction: &Direction) -> Option<Position>;
+}
+
+/// `down` and `up` change the depth directly.
+pub struct DirectSteering;
+
+impl SteeringModel for DirectSteering {
+    fn steer(&self, position: &Position, direction: &Direction) -> Option<Position> {
+        let mut next = *position;
+        match *direction {
+            Direction::Forward(stride) => next.horizontal = next.horizontal.checked_add(stride)?,
+            Direction::Down(stride) => next.depth = next.depth.checked_add(stride)?,
+            Direction::Up(stride) => next.depth = next.depth.checked_sub(stride)?,
+        }
+        Some(next)
+    }
+}
+
+/// `down` and `up` change the aim, which determines how much `forward` dives.
+pub struct AimSteering;
+
+impl SteeringModel for AimSteering {
+    fn steer(&self, position: &Position, direction: &Direction) -> Option<Position> {
+        let mut next = *position;
+        match *direction {
+            Direction::Forward(stride) => {
+                next.horizontal = next.horizontal.checked_add(stride)?;
+                next.depth = next.depth.checked_add(stride.checked_mul(next.aim)?)?;
+            }
+            Direction::Down(stride) => next.aim = next.aim.checked_add(stride)?,
+            Direction::Up(stride) => next.aim = next.aim.checked_sub(stride)?,
+        }
+        Some(next)
+    }
+}
+
+/// Positions of the submarine, the start followed by the position after every command.
+#[derive(Clone, Debug)]
+pub struct Trajectory {
+    pub positions: Vec<Position>,
+}
+
+impl Trajectory {
+    pub fn final_position(&self) -> Position {
+        *self.positions.last().expect("trajectory without start")
+    }
+}
+
+/// A command that would take the submarine into an impossible state.
+#[derive(Debug, PartialEq, Eq)]
+pub enum NavigationError {
+    /// The command with the given 0-based index, written on `line`, would lift the submarine
+    /// above the surface
+    NegativeDepth {
+        command: usize,
+        line: usize,
+        depth: i32,
+    },
+    /// The command with the given 0-based index, written on `line`, would overflow a coordinate
+    Overflow { command: usize, line: usize },
+}
+
+impl Display for NavigationError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            NavigationError::NegativeDepth {
+                command,
+                line,
+                depth,
+            } => write!(
+                f,
+                "line {}: command {} would take the submarine above the surface (depth {})",
+                line, command, depth
+            ),
+            NavigationError::Overflow { command, line } => write!(
+                f,
+                "line {}: command {} would overflow the position",
+                line, command
+            ),
+        }
+    }
+}
+
+impl Error for NavigationError {}
+
+/// Follows the commands from `start` and records every position on the way.
+pub fn navigate<M: SteeringModel>(
+    model: &M,
+    commands: &[Command],
+    start: Position,
+) -> Result<Trajectory, NavigationError> {
+    let mut positions = Vec::with_capacity(commands.len() + 1);
+    positions.push(start);
+    let mut position = start;
+    for (command, &Command { line, direction }) in commands.iter().enumerate() {
+        position = model
+            .steer(&position, &direction)
+            .ok_or(NavigationError::Overflow { command, line })?;
+        if position.depth < 0 {
+            return Err(NavigationError::NegativeDepth {
+                command,
+                line,
+                depth: position.depth,
+            });
+        }
+        positions.push(position);
+    }
+    Ok(Trajectory { positions })
+}
+
+/// Multiplies horizontal position and depth of the submarine, the puzzle's answer.
+fn product(position: Position) -> Result<i32> {
+    position
+        .horizontal
+        .checked_mul(position.depth)
+        .ok_or_else(|| anyhow!("product of {:?} overflows", position))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Dive!";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Vec<Command>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_script(input)?)
+    }
+
+    fn part1(commands: &Self::Input) -> Result<i32> {
+        let trajectory = navigate(&DirectSteering, commands, Position::default())?;
+        product(trajectory.final_position())
+    }
+
+    fn part2(commands: &Self::Input) -> Result<i32> {
+        let trajectory = navigate(&AimSteering, commands, Position::default())?;
+        product(trajectory.final_position())
+    }
+
+    fn input_stats(commands: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("commands", commands.len() as i64)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directions(commands: &[Command]) -> Vec<(usize, Direction)> {
+        commands.iter().map(|c| (c.line, c.direction)).collect()
+    }
+
+    fn error_kind(script: &str) -> (usize, ParseDirectionErrorKind) {
+        let error = parse_script(script).unwrap_err();
+        (error.line, error.kind)
+    }
+
+    #[test]
+    fn example() {
+        let commands = Day02::parse(include_str!("../example")).unwrap();
+        assert_eq!(Day02::part1(&commands).unwrap(), 150);
+        assert_eq!(Day02::part2(&commands).unwrap(), 900);
+    }
+
+    #[test]
+    fn nested_repeat() {
+        use Direction::*;
+        let script = "forward 1\nrepeat 2 {\n  down 3\n  repeat 2 {up 1}\n}\n";
+        assert_eq!(
+            directions(&parse_script(script).unwrap()),
+            vec![
+                (1, Forward(1)),
+                (3, Down(3)),
+                (4, Up(1)),
+                (4, Up(1)),
+                (3, Down(3)),
+                (4, Up(1)),
+                (4, Up(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn unclosed_block() {
+        assert_eq!(
+            error_kind("forward 1\nrepeat 2 {\n  down 3\n"),
+            (2, ParseDirectionErrorKind::UnclosedBlock)
+        );
+    }
+
+    #[test]
+    fn stray_brace() {
+        assert_eq!(
+            error_kind("forward 1\n}\ndown 2\n"),
+            (2, ParseDirectionErrorKind::UnmatchedBrace)
+        );
+    }
+
+    #[test]
+    fn too_many_commands() {
+        let script = format!("repeat {} {{ forward 1 }}", MAX_COMMANDS + 1);
+        assert_eq!(
+            error_kind(&script),
+            (1, ParseDirectionErrorKind::TooManyCommands)
+        );
+        let script = format!("repeat {} {{ forward 1 }}", usize::MAX);
+        assert_eq!(
+            error_kind(&script),
+            (1, ParseDirectionErrorKind::TooManyCommands)
+        );
+        assert_eq!(
+            parse_script(&format!("repeat {} {{ }}", usize::MAX)).unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn above_surface() {
+        let commands = parse_script("down 2\nforward 1\nup 3\n").unwrap();
+        assert_eq!(
+            navigate(&DirectSteering, &commands, Position::default()).unwrap_err(),
+            NavigationError::NegativeDepth {
+                command: 2,
+                line: 3,
+                depth: -1
+            }
+        );
+    }
+}
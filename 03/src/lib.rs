@@ -0,0 +1,442 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result};
+use solution::Solution;
+
+/// Unsigned number with a fixed number of bits, stored as 64-bit words from least significant.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bits {
+    width: usize,
+    words: Vec<u64>,
+}
+
+impl Bits {
+    pub fn zero(width: usize) -> Self {
+        Bits {
+            width,
+            words: vec![0; width.div_ceil(64)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Bit at position `pos`, counted from the least significant bit.
+    pub fn get(&self, pos: usize) -> bool {
+        assert!(pos < self.width, "bit {} out of range", pos);
+        self.words[pos / 64] >> (pos % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, pos: usize, bit: bool) {
+        assert!(pos < self.width, "bit {} out of range", pos);
+        if bit {
+            self.words[pos / 64] |= 1 << (pos % 64);
+        } else {
+            self.words[pos / 64] &= !(1 << (pos % 64));
+        }
+    }
+
+    /// Bit in column `column` of the binary representation, column 0 is the most significant bit.
+    pub fn column(&self, column: usize) -> bool {
+        self.get(self.width - 1 - column)
+    }
+
+    /// Flips all bits within the width.
+    pub fn inverted(&self) -> Bits {
+        let mut words: Vec<u64> = self.words.iter().map(|w| !w).collect();
+        if !self.width.is_multiple_of(64) {
+            if let Some(last) = words.last_mut() {
+                *last &= (1 << (self.width % 64)) - 1;
+            }
+        }
+        Bits {
+            width: self.width,
+            words,
+        }
+    }
+
+    /// Product of both numbers, wide enough to never overflow.
+    pub fn product(&self, other: &Bits) -> Bits {
+        let mut product = Bits::zero(self.width + other.width);
+        for (i, &a) in self.words.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.words.iter().enumerate() {
+                let sum = a as u128 * b as u128 + product.words[i + j] as u128 + carry;
+                product.words[i + j] = sum as u64;
+                carry = sum >> 64;
+            }
+            let mut k = i + other.words.len();
+            while carry > 0 {
+                let sum = product.words[k] as u128 + carry;
+                product.words[k] = sum as u64;
+                carry = sum >> 64;
+                k += 1;
+            }
+        }
+        product
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.words.split_first() {
+            None => Some(0),
+            Some((&low, high)) if high.iter().all(|&w| w == 0) => Some(low),
+            Some(_) => None,
+        }
+    }
+}
+
+/// The number in decimal.
+impl Display for Bits {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        // Repeatedly divide by 10^19 and collect the remainders as 19-digit chunks
+        let mut words = self.words.clone();
+        let mut chunks = Vec::new();
+        while words.iter().any(|&w| w != 0) {
+            let mut remainder = 0u128;
+            for word in words.iter_mut().rev() {
+                let current = remainder << 64 | *word as u128;
+                *word = (current / CHUNK as u128) as u64;
+                remainder = current % CHUNK as u128;
+            }
+            chunks.push(remainder as u64);
+        }
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((most_significant, rest)) => {
+                write!(f, "{}", most_significant)?;
+                for chunk in rest.iter().rev() {
+                    write!(f, "{:019}", chunk)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseBitsError {
+    pub position: usize,
+    pub found: char,
+}
+
+impl Display for ParseBitsError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected 0 or 1 at position {}, found {:?}",
+            self.position, self.found
+        )
+    }
+}
+
+impl Error for ParseBitsError {}
+
+/// Parses a binary number whose width is the number of digits.
+impl FromStr for Bits {
+    type Err = ParseBitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bits = Bits::zero(s.chars().count());
+        let width = bits.width;
+        for (column, c) in s.chars().enumerate() {
+            match c {
+                '0' => {}
+                '1' => bits.set(width - 1 - column, true),
+                found => {
+                    return Err(ParseBitsError {
+                        position: column,
+                        found,
+                    })
+                }
+            }
+        }
+        Ok(bits)
+    }
+}
+
+/// Readings of the diagnostic report, which all have the same width.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub width: usize,
+    pub readings: Vec<Bits>,
+}
+
+/// How often each bit value occurs in one column of the report.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BitCount {
+    pub zeros: usize,
+    pub ones: usize,
+}
+
+impl BitCount {
+    fn of_column(readings: &[Bits], column: usize) -> Self {
+        let ones = readings.iter().filter(|r| r.column(column)).count();
+        BitCount {
+            zeros: readings.len() - ones,
+            ones,
+        }
+    }
+
+    pub fn most_common(&self, tie_break: TieBreak) -> Option<bool> {
+        if self.ones > self.zeros {
+            Some(true)
+        } else if self.zeros > self.ones {
+            Some(false)
+        } else {
+            match tie_break {
+                TieBreak::One => Some(true),
+                TieBreak::Zero => Some(false),
+                TieBreak::Reject => None,
+            }
+        }
+    }
+}
+
+/// Bit that counts as the most common one when a column has as many zeros as ones.
+///
+/// The least common bit is always the other one, so [`TieBreak::One`] gives the puzzle's rules
+/// of keeping ones for the oxygen rating and zeros for the CO2 rating.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TieBreak {
+    One,
+    Zero,
+    /// Ties are an error
+    Reject,
+}
+
+/// Everything derived from a report.
+#[derive(Clone, Debug)]
+pub struct Diagnostics {
+    /// Bit counts of every column, from the most significant one
+    pub histogram: Vec<BitCount>,
+    pub gamma_rate: Bits,
+    pub epsilon_rate: Bits,
+    pub power_consumption: Bits,
+    pub o2_rating: Bits,
+    pub co2_rating: Bits,
+    pub life_support_rating: Bits,
+}
+
+pub fn histogram(report: &Report) -> Vec<BitCount> {
+    (0..report.width)
+        .map(|column| BitCount::of_column(&report.readings, column))
+        .collect()
+}
+
+pub fn find_power_consumption(report: &Report, tie_break: TieBreak) -> Result<(Bits, Bits, Bits)> {
+    let mut gamma_rate = Bits::zero(report.width);
+    for (column, count) in histogram(report).iter().enumerate() {
+        let bit = count
+            .most_common(tie_break)
+            .ok_or_else(|| anyhow!("column {} has as many zeros as ones", column))?;
+        gamma_rate.set(report.width - 1 - column, bit);
+    }
+    let epsilon_rate = gamma_rate.inverted();
+    let power_consumption = gamma_rate.product(&epsilon_rate);
+    Ok((gamma_rate, epsilon_rate, power_consumption))
+}
+
+pub fn find_life_support_rating(
+    report: &Report,
+    tie_break: TieBreak,
+) -> Result<(Bits, Bits, Bits)> {
+    let o2_rating = find_by_bit_pattern(report, tie_break, true)?;
+    let co2_rating = find_by_bit_pattern(report, tie_break, false)?;
+    let life_support_rating = o2_rating.product(&co2_rating);
+    Ok((o2_rating, co2_rating, life_support_rating))
+}
+
+/// Filters the readings column by column, keeping those with the most common bit, or the least
+/// common one if `keep_most_common` is false, until one reading is left.
+///
+/// A column in which all remaining readings agree does not remove any of them.
+fn find_by_bit_pattern(
+    report: &Report,
+    tie_break: TieBreak,
+    keep_most_common: bool,
+) -> Result<Bits> {
+    let mut readings = report.readings.clone();
+    for column in 0..report.width {
+        if readings.len() <= 1 {
+            break;
+        }
+        let count = BitCount::of_column(&readings, column);
+        if count.zeros == 0 || count.ones == 0 {
+            continue;
+        }
+        let most_common = count
+            .most_common(tie_break)
+            .ok_or_else(|| anyhow!("column {} has as many zeros as ones", column))?;
+        readings.retain(|r| r.column(column) == (most_common == keep_most_common));
+    }
+    match readings.len() {
+        1 => Ok(readings.remove(0)),
+        0 => bail!("the report is empty"),
+        n => bail!("{} identical readings remain", n),
+    }
+}
+
+pub fn diagnose(report: &Report, tie_break: TieBreak) -> Result<Diagnostics> {
+    let (gamma_rate, epsilon_rate, power_consumption) = find_power_consumption(report, tie_break)?;
+    let (o2_rating, co2_rating, life_support_rating) = find_life_support_rating(report, tie_break)?;
+    Ok(Diagnostics {
+        histogram: histogram(report),
+        gamma_rate,
+        epsilon_rate,
+        power_consumption,
+        o2_rating,
+        co2_rating,
+        life_support_rating,
+    })
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Report;
+    type Answer1 = Bits;
+    type Answer2 = Bits;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let readings = input
+            .lines()
+            .enumerate()
+            .map(|(idx, s)| {
+                s.trim()
+                    .parse::<Bits>()
+                    .map_err(|e| anyhow!("line {}: invalid diagnostic {:?}: {}", idx + 1, s, e))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // The width of the report is the width of its first reading
+        let width = readings.first().map(Bits::width).unwrap_or(0);
+        if width == 0 {
+            bail!("the report has no readings");
+        }
+        if let Some(idx) = readings.iter().position(|r| r.width() != width) {
+            bail!(
+                "line {}: reading has {} bits, but the first one has {}",
+                idx + 1,
+                readings[idx].width(),
+                width
+            );
+        }
+        Ok(Report { width, readings })
+    }
+
+    fn part1(report: &Self::Input) -> Result<Bits> {
+        let (_gamma_rate, _epsilon_rate, power_consumption) =
+            find_power_consumption(report, TieBreak::One)?;
+        Ok(power_consumption)
+    }
+
+    fn part2(report: &Self::Input) -> Result<Bits> {
+        let (_o2_rating, _co2_rating, life_support_rating) =
+            find_life_support_rating(report, TieBreak::One)?;
+        Ok(life_support_rating)
+    }
+
+    fn input_stats(report: &Self::Input) -> Vec<(&'static str, i64)> {
+        let histogram = histogram(report);
+        let tied = histogram.iter().filter(|c| c.zeros == c.ones).count();
+        let mut stats = vec![
+            ("numbers", report.readings.len() as i64),
+            ("width", report.width as i64),
+            ("tied columns", tied as i64),
+        ];
+        // Ratings are only shown if the puzzle's rules find them and they fit
+        if let Ok(diagnostics) = diagnose(report, TieBreak::One) {
+            let ratings = [
+                ("gamma rate", &diagnostics.gamma_rate),
+                ("epsilon rate", &diagnostics.epsilon_rate),
+                ("oxygen generator rating", &diagnostics.o2_rating),
+                ("CO2 scrubber rating", &diagnostics.co2_rating),
+            ];
+            for (name, rating) in ratings {
+                if let Some(value) = rating.to_u64().and_then(|v| i64::try_from(v).ok()) {
+                    stats.push((name, value));
+                }
+            }
+        }
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(readings: &[&str]) -> Report {
+        Day03::parse(&readings.join("\n")).unwrap()
+    }
+
+    fn values(diagnostics: &Diagnostics) -> [Option<u64>; 6] {
+        [
+            diagnostics.gamma_rate.to_u64(),
+            diagnostics.epsilon_rate.to_u64(),
+            diagnostics.power_consumption.to_u64(),
+            diagnostics.o2_rating.to_u64(),
+            diagnostics.co2_rating.to_u64(),
+            diagnostics.life_support_rating.to_u64(),
+        ]
+    }
+
+    #[test]
+    fn example() {
+        let report = Day03::parse(include_str!("../example")).unwrap();
+        let diagnostics = diagnose(&report, TieBreak::One).unwrap();
+        assert_eq!(values(&diagnostics), [22, 9, 198, 23, 10, 230].map(Some));
+        assert_eq!(diagnostics.histogram[0], BitCount { zeros: 5, ones: 7 });
+        assert_eq!(Day03::part1(&report).unwrap().to_string(), "198");
+        assert_eq!(Day03::part2(&report).unwrap().to_string(), "230");
+    }
+
+    #[test]
+    fn tie_breaks() {
+        let report = report(&["10", "01"]);
+        let one = diagnose(&report, TieBreak::One).unwrap();
+        assert_eq!(values(&one), [3, 0, 0, 2, 1, 2].map(Some));
+        let zero = diagnose(&report, TieBreak::Zero).unwrap();
+        assert_eq!(values(&zero), [0, 3, 0, 1, 2, 2].map(Some));
+        assert!(diagnose(&report, TieBreak::Reject).is_err());
+    }
+
+    #[test]
+    fn rejected_tie_while_filtering() {
+        // The columns of the whole report are not tied, but those of the remaining readings are
+        let report = report(&["110", "101", "000"]);
+        assert!(find_power_consumption(&report, TieBreak::Reject).is_ok());
+        assert!(find_life_support_rating(&report, TieBreak::Reject).is_err());
+    }
+
+    #[test]
+    fn wider_than_128_bits() {
+        let high = format!("1{}", "0".repeat(129));
+        let high_and_low = format!("1{}1", "0".repeat(128));
+        let report = report(&[&high, &high_and_low, &"0".repeat(130)]);
+        let diagnostics = diagnose(&report, TieBreak::One).unwrap();
+        assert_eq!(diagnostics.gamma_rate, high.parse().unwrap());
+        assert_eq!(
+            diagnostics.epsilon_rate.to_string(),
+            "680564733841876926926749214863536422911"
+        );
+        assert_eq!(
+            diagnostics.power_consumption.to_string(),
+            "463168356949264781694283940034751631412399373928720379230903586816788982136832"
+        );
+        assert_eq!(
+            diagnostics.o2_rating.to_string(),
+            "680564733841876926926749214863536422913"
+        );
+        assert_eq!(diagnostics.co2_rating.to_u64(), Some(0));
+        assert_eq!(diagnostics.life_support_rating.width(), 260);
+    }
+}
@@ -0,0 +1,180 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use input_parser::FieldError;
+use solution::Solution;
+
+#[derive(Clone, Copy, Debug)]
+enum MarkedNumber {
+    Marked,
+    Unmarked(u32),
+}
+
+impl FromStr for MarkedNumber {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(MarkedNumber::Unmarked(s.parse::<u32>()?))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Board {
+    fields: [[MarkedNumber; 5]; 5],
+}
+
+impl Board {
+    fn new(repr: &[String]) -> std::result::Result<Self, ParseBoardError> {
+        let repr: Vec<Vec<MarkedNumber>> = repr
+            .iter()
+            .map(|s| input_parser::split_whitespace_list(s))
+            .collect::<std::result::Result<_, _>>()
+            .map_err(ParseBoardError::ParseNumber)?;
+        if repr.len() != 5 {
+            return Err(ParseBoardError::UnexpectedLength(repr.len()));
+        }
+        let mut fields = [[MarkedNumber::Unmarked(0); 5]; 5];
+        for (i, row) in repr.iter().enumerate() {
+            if row.len() != 5 {
+                return Err(ParseBoardError::UnexpectedWidth(row.len()));
+            }
+            for (j, number) in row.iter().enumerate() {
+                fields[i][j] = *number;
+            }
+        }
+        Ok(Board { fields })
+    }
+
+    fn mark(&mut self, number: u32) {
+        for i in 0..5 {
+            for j in 0..5 {
+                if let MarkedNumber::Unmarked(x) = self.fields[i][j] {
+                    if x == number {
+                        self.fields[i][j] = MarkedNumber::Marked
+                    }
+                }
+            }
+        }
+    }
+
+    fn has_won(&self) -> bool {
+        let mut won = false;
+        for i in 0..5 {
+            let mut row_fully_marked = true;
+            let mut col_fully_marked = true;
+            for j in 0..5 {
+                row_fully_marked &= matches!(self.fields[i][j], MarkedNumber::Marked);
+                col_fully_marked &= matches!(self.fields[j][i], MarkedNumber::Marked);
+            }
+            won |= row_fully_marked | col_fully_marked;
+        }
+        won
+    }
+
+    fn score(&self) -> u32 {
+        self.fields
+            .iter()
+            .flat_map(|row| row.iter())
+            .filter_map(|n| match n {
+                MarkedNumber::Unmarked(x) => Some(x),
+                _ => None,
+            })
+            .sum()
+    }
+}
+
+#[derive(Debug)]
+enum ParseBoardError {
+    ParseNumber(FieldError<ParseIntError>),
+    UnexpectedWidth(usize),
+    UnexpectedLength(usize),
+}
+
+impl Display for ParseBoardError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::ParseNumber(e) => write!(f, "invalid number: {}", e),
+            Self::UnexpectedWidth(width) => write!(f, "expected 5 numbers per row, got {}", width),
+            Self::UnexpectedLength(length) => write!(f, "expected 5 rows, got {}", length),
+        }
+    }
+}
+
+impl Error for ParseBoardError {}
+
+pub struct Bingo {
+    drawn_numbers: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Giant Squid";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Bingo;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut sections = input_parser::Sections::from(input);
+        let drawn_numbers: String = sections.header()?;
+        let drawn_numbers = input_parser::split_list(&drawn_numbers, ",")?;
+        let boards = sections
+            .map(|s| Board::new(s.lines()))
+            .collect::<std::result::Result<_, _>>()?;
+        Ok(Bingo {
+            drawn_numbers,
+            boards,
+        })
+    }
+
+    fn part1(bingo: &Self::Input) -> Result<u32> {
+        let scores = play_bingo(&bingo.drawn_numbers, &mut bingo.boards.clone());
+        scores
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow!("no board has won"))
+    }
+
+    fn part2(bingo: &Self::Input) -> Result<u32> {
+        let scores = play_bingo(&bingo.drawn_numbers, &mut bingo.boards.clone());
+        scores
+            .last()
+            .copied()
+            .ok_or_else(|| anyhow!("no board has won"))
+    }
+
+    fn input_stats(bingo: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![
+            ("drawn numbers", bingo.drawn_numbers.len() as i64),
+            ("boards", bingo.boards.len() as i64),
+        ]
+    }
+}
+
+/// Returns the scores of the boards in the order in which they win.
+fn play_bingo(drawn_numbers: &[u32], boards: &mut Vec<Board>) -> Vec<u32> {
+    let mut winning_boards: Vec<u32> = Vec::new();
+    for number in drawn_numbers {
+        if boards.is_empty() {
+            break;
+        }
+        for board in boards.iter_mut() {
+            board.mark(*number)
+        }
+        winning_boards.extend(
+            boards
+                .iter()
+                .filter(|b| b.has_won())
+                .map(|b| b.score() * number),
+        );
+        boards.retain(|b| !b.has_won());
+    }
+    winning_boards
+}
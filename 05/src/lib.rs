@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use geometry::{Line2, Point2};
+use input_parser::FromPattern;
+use solution::Solution;
+
+#[derive(Debug, FromPattern)]
+#[pattern("{start} -> {end}")]
+pub struct Line {
+    start: Point2,
+    end: Point2,
+}
+
+impl Line {
+    fn segment(&self) -> Line2 {
+        Line2::new(self.start, self.end)
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Vec<Line>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input_parser::parse_str(input)?)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<u32> {
+        let h_v_lines: Vec<_> = lines
+            .iter()
+            .filter(|&l| l.segment().is_axis_aligned())
+            .collect();
+        Ok(find_num_points_covered_by_at_least_two_lines(&h_v_lines))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<u32> {
+        let h_v_d_lines: Vec<_> = lines
+            .iter()
+            .filter(|&l| {
+                let segment = l.segment();
+                segment.is_axis_aligned() | segment.is_diagonal()
+            })
+            .collect();
+        Ok(find_num_points_covered_by_at_least_two_lines(&h_v_d_lines))
+    }
+
+    fn input_stats(lines: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("lines", lines.len() as i64)]
+    }
+}
+
+fn find_num_points_covered_by_at_least_two_lines(lines: &[&Line]) -> u32 {
+    let mut covered_points: HashMap<Point2, u32> = HashMap::new();
+    for line in lines {
+        let points = line.segment().points();
+        for point in points {
+            covered_points.insert(
+                point,
+                covered_points.get(&point).map(|cnt| cnt + 1).unwrap_or(1),
+            );
+        }
+    }
+    covered_points.values().filter(|&cnt| *cnt > 1).count() as u32
+}
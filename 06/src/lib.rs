@@ -0,0 +1,113 @@
+use std::ops;
+
+use anyhow::{bail, Result};
+use solution::Solution;
+
+#[derive(Debug)]
+struct RingArray<T: Sized, const N: usize> {
+    data: [T; N],
+}
+
+impl<T: Sized, const N: usize> RingArray<T, N> {
+    fn from(data: [T; N]) -> Self {
+        RingArray { data }
+    }
+
+    fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+}
+
+impl<T: Sized, const N: usize> ops::Index<usize> for RingArray<T, N> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.data[i % N]
+    }
+}
+
+impl<T: Sized, const N: usize> ops::IndexMut<usize> for RingArray<T, N> {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.data[i % N]
+    }
+}
+
+#[derive(Debug)]
+struct FishSimulation {
+    day: usize,
+    fishes: RingArray<u128, 7>,
+    new_fishes: RingArray<u128, 3>,
+}
+
+impl FishSimulation {
+    fn new(fishes: [u128; 7]) -> Self {
+        FishSimulation {
+            day: 0,
+            fishes: RingArray::from(fishes),
+            new_fishes: RingArray::from([0; 3]),
+        }
+    }
+
+    fn advance_day(&mut self) {
+        self.spawn_new_fishes();
+        self.grow_up_new_fishes();
+        self.day += 1;
+    }
+
+    fn count_fishes(&self) -> u128 {
+        self.fishes.iter().sum::<u128>() + self.new_fishes.iter().sum::<u128>()
+    }
+
+    fn grow_up_new_fishes(&mut self) {
+        self.fishes[self.day + 7] += self.new_fishes[self.day];
+        self.new_fishes[self.day] = 0
+    }
+
+    fn spawn_new_fishes(&mut self) {
+        self.new_fishes[self.day + 2] = self.fishes[self.day]
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Lanternfish";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = [u128; 7];
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut initial_fishes: [u128; 7] = [0; 7];
+        let fishes: Vec<usize> = input_parser::split_list(input.trim(), ",")?;
+        for fish in fishes {
+            if fish >= initial_fishes.len() {
+                bail!("fish timer {} out of range", fish);
+            }
+            initial_fishes[fish] += 1;
+        }
+        Ok(initial_fishes)
+    }
+
+    fn part1(initial_fishes: &Self::Input) -> Result<u128> {
+        Ok(simulate_fish_growth(*initial_fishes, 80))
+    }
+
+    fn part2(initial_fishes: &Self::Input) -> Result<u128> {
+        Ok(simulate_fish_growth(*initial_fishes, 256))
+    }
+
+    fn input_stats(timers: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("fish", timers.iter().sum::<u128>() as i64)]
+    }
+}
+
+fn simulate_fish_growth(initial_fishes: [u128; 7], days: u32) -> u128 {
+    let mut fishes = FishSimulation::new(initial_fishes);
+    for _ in 0..days {
+        fishes.advance_day();
+    }
+    fishes.count_fishes()
+}
@@ -0,0 +1,164 @@
+use anyhow::{bail, Result};
+use solution::Solution;
+
+/// Position all crabs move to and the fuel they need for it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Alignment {
+    pub position: i64,
+    pub fuel: u64,
+}
+
+/// Fuel to move one step per unit of distance.
+pub fn linear_cost(distance: u64) -> u64 {
+    distance
+}
+
+/// Fuel to move `distance` steps when every step costs one more than the previous one.
+pub fn triangular_cost(distance: u64) -> u64 {
+    distance * (distance + 1) / 2
+}
+
+/// Fuel all crabs need to move to `position`, if moving a distance `d` costs `cost(d)`.
+pub fn total_fuel(positions: &[i64], position: i64, cost: impl Fn(u64) -> u64) -> u64 {
+    positions.iter().map(|&p| cost(p.abs_diff(position))).sum()
+}
+
+/// Cheapest alignment for the linear cost, which is attained at the median.
+///
+/// `positions` must be sorted and not empty.
+pub fn linear_alignment(positions: &[i64]) -> Alignment {
+    let position = positions[(positions.len() - 1) / 2];
+    Alignment {
+        position,
+        fuel: total_fuel(positions, position, linear_cost),
+    }
+}
+
+/// Cheapest alignment for the triangular cost.
+///
+/// The real-valued optimum lies within half a step of the mean, so the best integer position
+/// is one of the integers next to that interval. `positions` must not be empty.
+pub fn triangular_alignment(positions: &[i64]) -> Alignment {
+    let mean = positions
+        .iter()
+        .sum::<i64>()
+        .div_euclid(positions.len() as i64);
+    (mean - 1..=mean + 2)
+        .map(|position| Alignment {
+            position,
+            fuel: total_fuel(positions, position, triangular_cost),
+        })
+        .min_by_key(|a| (a.fuel, a.position))
+        .unwrap()
+}
+
+/// Cheapest alignment for any cost that is convex and non-decreasing in the distance.
+///
+/// The total fuel is then convex in the position, so a binary search for the first position
+/// from which moving right no longer saves fuel finds the optimum. `positions` must be sorted
+/// and not empty.
+pub fn convex_alignment(positions: &[i64], cost: impl Fn(u64) -> u64) -> Alignment {
+    let fuel = |position| total_fuel(positions, position, &cost);
+    let (mut low, mut high) = (positions[0], positions[positions.len() - 1]);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if fuel(mid + 1) < fuel(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    Alignment {
+        position: low,
+        fuel: fuel(low),
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Vec<i64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut positions: Vec<i64> = input_parser::split_list(input.trim(), ",")?;
+        if positions.is_empty() {
+            bail!("no crab positions given");
+        }
+        positions.sort_unstable();
+        Ok(positions)
+    }
+
+    fn part1(positions: &Self::Input) -> Result<u64> {
+        Ok(linear_alignment(positions).fuel)
+    }
+
+    fn part2(positions: &Self::Input) -> Result<u64> {
+        Ok(triangular_alignment(positions).fuel)
+    }
+
+    fn input_stats(positions: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("crabs", positions.len() as i64)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(positions: &[i64], cost: impl Fn(u64) -> u64) -> u64 {
+        (positions[0]..=positions[positions.len() - 1])
+            .map(|position| total_fuel(positions, position, &cost))
+            .min()
+            .unwrap()
+    }
+
+    fn inputs() -> Vec<Vec<i64>> {
+        let example = Day07::parse(include_str!("../example")).unwrap();
+        let shifted = example.iter().map(|p| p - 10).collect();
+        vec![example, shifted, vec![-7, -3, -3, 0, 5, 12], vec![-4]]
+    }
+
+    #[test]
+    fn example() {
+        let positions = Day07::parse(include_str!("../example")).unwrap();
+        assert_eq!(linear_alignment(&positions).fuel, 37);
+        assert_eq!(triangular_alignment(&positions).fuel, 168);
+    }
+
+    #[test]
+    fn convex_alignment_matches_brute_force() {
+        let cubic = |d: u64| d * d * d;
+        for positions in inputs() {
+            let costs: [&dyn Fn(u64) -> u64; 3] = [&linear_cost, &triangular_cost, &cubic];
+            for cost in costs {
+                let alignment = convex_alignment(&positions, cost);
+                assert_eq!(
+                    alignment.fuel,
+                    brute_force(&positions, cost),
+                    "{:?}",
+                    positions
+                );
+                assert_eq!(
+                    alignment.fuel,
+                    total_fuel(&positions, alignment.position, cost)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn closed_forms_match_convex_alignment() {
+        for positions in inputs() {
+            let linear = convex_alignment(&positions, linear_cost);
+            assert_eq!(linear_alignment(&positions).fuel, linear.fuel);
+            let triangular = convex_alignment(&positions, triangular_cost);
+            assert_eq!(triangular_alignment(&positions).fuel, triangular.fuel);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use day07::Day07;
+
+fn main() -> anyhow::Result<()> {
+    solution::main::<Day07>()
+}
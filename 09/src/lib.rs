@@ -0,0 +1,140 @@
+use std::collections::VecDeque;
+use std::fmt::{self, Display, Formatter};
+
+use anyhow::{bail, Result};
+use input_parser::Grid;
+use recorder::{Frame, Recorder};
+use solution::Solution;
+
+type Index = (usize, usize);
+
+/// Height of the locations that belong to no basin
+const RIDGE: u8 = 9;
+
+/// Locations that are strictly lower than all of their horizontal and vertical neighbors.
+pub fn low_points(heights: &Grid<u8>) -> Vec<Index> {
+    heights
+        .iter()
+        .filter(|&((i, j), &h)| {
+            heights
+                .neighbors4((i as isize, j as isize))
+                .all(|(_, &n)| h < n)
+        })
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Labeling of the height map by basin, basin `b` is the one that drains into `low_points[b]`.
+///
+/// A basin with several low points is listed once, with the first of them in row-major order.
+pub struct Basins {
+    pub low_points: Vec<Index>,
+    /// Basin of each location, `None` for the ridges between basins
+    pub labels: Grid<Option<usize>>,
+    /// Number of locations in each basin
+    pub sizes: Vec<usize>,
+}
+
+impl Basins {
+    /// Flood-fills the basins breadth-first from their low points up to the ridges of height 9.
+    pub fn find(heights: &Grid<u8>) -> Self {
+        let mut labels = heights.map(|_| None);
+        let mut low_points = Vec::new();
+        let mut sizes = Vec::new();
+        for low_point in self::low_points(heights) {
+            // Filled from an earlier low point of the same basin
+            if labels[low_point].is_some() {
+                continue;
+            }
+            let basin = low_points.len();
+            low_points.push(low_point);
+            let mut size = 0;
+            let mut queue = VecDeque::from(vec![low_point]);
+            labels[low_point] = Some(basin);
+            while let Some((i, j)) = queue.pop_front() {
+                size += 1;
+                for ((ni, nj), &h) in heights.neighbors4((i as isize, j as isize)) {
+                    let next = (ni as usize, nj as usize);
+                    if h < RIDGE && labels[next].is_none() {
+                        labels[next] = Some(basin);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+        Basins {
+            low_points,
+            labels,
+            sizes,
+        }
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_text(&self.to_string())
+    }
+}
+
+/// Draws every basin with its own letter (reused after 52 basins) and the ridges as `.`.
+impl Display for Basins {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        for (i, row) in self.labels.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for label in row {
+                let c = match label {
+                    Some(basin) => LETTERS[basin % LETTERS.len()] as char,
+                    None => '.',
+                };
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Grid<u8>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(heights: &Self::Input) -> Result<u32> {
+        Ok(low_points(heights)
+            .into_iter()
+            .map(|idx| heights[idx] as u32 + 1)
+            .sum())
+    }
+
+    fn part2(heights: &Self::Input) -> Result<usize> {
+        let mut sizes = Basins::find(heights).sizes;
+        if sizes.len() < 3 {
+            bail!("expected at least three basins, found {}", sizes.len());
+        }
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(sizes[..3].iter().product())
+    }
+
+    fn visualize(heights: &Self::Input, recorder: &mut Recorder) -> Result<()> {
+        recorder.record(|| Basins::find(heights).frame());
+        Ok(())
+    }
+
+    fn input_stats(heights: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![
+            ("rows", heights.height() as i64),
+            ("columns", heights.width() as i64),
+        ]
+    }
+}
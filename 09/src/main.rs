@@ -0,0 +1,5 @@
+use day09::Day09;
+
+fn main() -> anyhow::Result<()> {
+    solution::main::<Day09>()
+}
@@ -0,0 +1,120 @@
+use solution::Solution;
+
+#[derive(Debug)]
+enum ParseChunkError {
+    MissingTokens(Vec<char>),
+    UnexpectedToken(char, char, u32),
+}
+
+fn parse_chunk(chunk: &str) -> Result<(), ParseChunkError> {
+    // This is a CFL, so as it can be parsed with a PDA using one stack is enough
+    // Grammar: S ::= epsilon | (S) | [S] | {S} | <S>
+    let mut stack = Vec::new();
+    for c in chunk.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => stack.push(c),
+            x => match stack.pop().and_then(matching_bracket) {
+                Some(y) if y == x => (),
+                Some(z) => return Err(ParseChunkError::UnexpectedToken(z, x, unexpected_score(x))),
+                None => {
+                    return Err(ParseChunkError::UnexpectedToken(
+                        'e',
+                        x,
+                        unexpected_score(x),
+                    ))
+                }
+            },
+        }
+    }
+    if !stack.is_empty() {
+        Err(ParseChunkError::MissingTokens(
+            stack
+                .iter()
+                .filter_map(|&c| matching_bracket(c))
+                .rev()
+                .collect(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn unexpected_score(c: char) -> u32 {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => 0,
+    }
+}
+
+fn missing_score(c: char) -> u32 {
+    match c {
+        ')' => 1,
+        ']' => 2,
+        '}' => 3,
+        '>' => 4,
+        _ => 0,
+    }
+}
+
+fn matching_bracket(c: char) -> Option<char> {
+    match c {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        '<' => Some('>'),
+        _ => None,
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(chunks: &Self::Input) -> anyhow::Result<u32> {
+        Ok(chunks
+            .iter()
+            .filter_map(|chunk| match parse_chunk(chunk) {
+                Err(ParseChunkError::UnexpectedToken(_expected, _got, score)) => Some(score),
+                _ => None,
+            })
+            .sum())
+    }
+
+    fn part2(chunks: &Self::Input) -> anyhow::Result<u128> {
+        let mut missing_scores: Vec<_> = chunks
+            .iter()
+            .filter_map(|chunk| match parse_chunk(chunk) {
+                Err(ParseChunkError::MissingTokens(tokens)) => Some(
+                    tokens
+                        .iter()
+                        .map(|&c| missing_score(c))
+                        .fold(0, |acc, s| acc * 5 + s as u128),
+                ),
+                _ => None,
+            })
+            .collect();
+        if missing_scores.is_empty() {
+            anyhow::bail!("no incomplete lines");
+        }
+        missing_scores.sort_unstable();
+        Ok(missing_scores[missing_scores.len() / 2])
+    }
+
+    fn input_stats(lines: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("lines", lines.len() as i64)]
+    }
+}
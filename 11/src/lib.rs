@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Result};
+use input_parser::Grid;
+use recorder::{Frame, Recorder};
+use solution::Solution;
+
+/// Energy level above which an octopus flashes
+const FLASH_LEVEL: u8 = 9;
+
+#[derive(Clone, Debug)]
+pub struct Octopuses {
+    energy: Grid<u8>,
+}
+
+impl Octopuses {
+    pub fn new(energy: Grid<u8>) -> Self {
+        Octopuses { energy }
+    }
+
+    pub fn energy(&self) -> &Grid<u8> {
+        &self.energy
+    }
+
+    pub fn len(&self) -> usize {
+        self.energy.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.energy.is_empty()
+    }
+
+    /// Advances by one step and returns the number of octopuses that flashed.
+    ///
+    /// Octopuses that reach the flash level are queued, so every flash only passes energy to
+    /// its eight neighbors instead of rescanning the whole grid.
+    pub fn step(&mut self) -> usize {
+        let mut queue = Vec::new();
+        for (idx, energy) in self.energy.iter_mut() {
+            *energy += 1;
+            if *energy == FLASH_LEVEL + 1 {
+                queue.push(idx);
+            }
+        }
+        let mut flashed = Vec::new();
+        while let Some((i, j)) = queue.pop() {
+            flashed.push((i, j));
+            let neighbors: Vec<_> = self
+                .energy
+                .neighbors8((i as isize, j as isize))
+                .map(|((ni, nj), _)| (ni as usize, nj as usize))
+                .collect();
+            for neighbor in neighbors {
+                let energy = &mut self.energy[neighbor];
+                // Only the increase that crosses the flash level triggers a flash
+                *energy += 1;
+                if *energy == FLASH_LEVEL + 1 {
+                    queue.push(neighbor);
+                }
+            }
+        }
+        for &idx in &flashed {
+            self.energy[idx] = 0;
+        }
+        flashed.len()
+    }
+
+    /// Iterator over the number of flashes in each of the following steps.
+    pub fn steps(&mut self) -> impl Iterator<Item = usize> + '_ {
+        std::iter::repeat_with(move || self.step())
+    }
+
+    /// Number of the first step in which all octopuses flash at once.
+    ///
+    /// `None` if the octopuses run into a cycle of states without such a step.
+    pub fn first_synchronized_step(mut self) -> Option<usize> {
+        let mut seen = HashSet::new();
+        let mut step = 0;
+        while seen.insert(self.energy.to_string()) {
+            step += 1;
+            if self.step() == self.len() {
+                return Some(step);
+            }
+        }
+        None
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_text(&self.energy.to_string())
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let energy: Grid<u8> = Grid::parse(input)?;
+        if energy.is_empty() {
+            bail!("no octopuses given");
+        }
+        Ok(energy)
+    }
+
+    fn part1(energy: &Self::Input) -> Result<usize> {
+        Ok(Octopuses::new(energy.clone()).steps().take(100).sum())
+    }
+
+    fn part2(energy: &Self::Input) -> Result<usize> {
+        match Octopuses::new(energy.clone()).first_synchronized_step() {
+            Some(step) => Ok(step),
+            None => bail!("the octopuses never flash at the same time"),
+        }
+    }
+
+    fn visualize(energy: &Self::Input, recorder: &mut Recorder) -> Result<()> {
+        let mut octopuses = Octopuses::new(energy.clone());
+        recorder.record(|| octopuses.frame());
+        loop {
+            let flashes = octopuses.step();
+            recorder.record(|| octopuses.frame());
+            if flashes == octopuses.len() || !recorder.is_recording() {
+                return Ok(());
+            }
+        }
+    }
+
+    fn input_stats(energy: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("octopuses", energy.len() as i64)]
+    }
+}
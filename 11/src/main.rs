@@ -0,0 +1,5 @@
+use day11::Day11;
+
+fn main() -> anyhow::Result<()> {
+    solution::main::<Day11>()
+}
@@ -0,0 +1,138 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use input_parser::FromPattern;
+use solution::Solution;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Node {
+    name: String,
+    large: bool,
+}
+
+impl Node {
+    fn start() -> Self {
+        Node {
+            name: String::from("start"),
+            large: false,
+        }
+    }
+
+    fn is_start(&self) -> bool {
+        self.name == "start"
+    }
+
+    fn is_end(&self) -> bool {
+        self.name == "end"
+    }
+}
+
+#[derive(Error, Debug)]
+enum ParseNodeError {
+    #[error("no node name given")]
+    MissingName,
+}
+
+impl FromStr for Node {
+    type Err = ParseNodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let first = s.chars().next().ok_or(ParseNodeError::MissingName)?;
+        Ok(Node {
+            name: String::from(s),
+            large: first.is_uppercase(),
+        })
+    }
+}
+
+#[derive(Debug, FromPattern)]
+#[pattern("{0}-{1}")]
+struct Edge(Node, Node);
+
+#[derive(Debug)]
+pub struct Graph {
+    edges: HashMap<Node, HashSet<Node>>,
+}
+
+impl Graph {
+    fn from(edges: Vec<Edge>) -> Self {
+        let mut edge_map = HashMap::new();
+        for Edge(n1, n2) in edges {
+            let connections_n1 = edge_map.entry(n1.clone()).or_insert_with(HashSet::new);
+            connections_n1.insert(n2.clone());
+            let connections_n2 = edge_map.entry(n2).or_insert_with(HashSet::new);
+            connections_n2.insert(n1);
+        }
+        Graph { edges: edge_map }
+    }
+
+    fn find_paths(&self) -> Vec<Vec<Node>> {
+        let mut stack = vec![Node::start()];
+        let mut paths = Vec::new();
+        self.dfs_helper(&mut stack, &mut paths, true);
+        paths
+    }
+
+    fn find_paths_two_small_caves_allowed(&self) -> Vec<Vec<Node>> {
+        let mut stack = vec![Node::start()];
+        let mut paths = Vec::new();
+        self.dfs_helper(&mut stack, &mut paths, false);
+        paths
+    }
+
+    fn dfs_helper(
+        &self,
+        stack: &mut Vec<Node>,
+        paths: &mut Vec<Vec<Node>>,
+        visited_two_small: bool,
+    ) {
+        let node = stack.last().expect("stack is empty");
+        if node.is_end() {
+            // Complete a path
+            paths.push(stack.clone());
+        } else {
+            let outgoing = self.edges.get(node).expect("missing edges");
+            for node in outgoing {
+                if node.large | !stack.contains(node) {
+                    stack.push(node.clone());
+                    self.dfs_helper(stack, paths, visited_two_small);
+                } else if !visited_two_small & !node.is_start() {
+                    // small cave that was already visited, but we are allowed to visit it again
+                    stack.push(node.clone());
+                    self.dfs_helper(stack, paths, true);
+                }
+            }
+        }
+        stack.pop();
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Graph;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(Graph::from(input_parser::parse_str(input)?))
+    }
+
+    fn part1(caves: &Self::Input) -> anyhow::Result<usize> {
+        Ok(caves.find_paths().len())
+    }
+
+    fn part2(caves: &Self::Input) -> anyhow::Result<usize> {
+        Ok(caves.find_paths_two_small_caves_allowed().len())
+    }
+
+    fn input_stats(graph: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("caves", graph.edges.len() as i64)]
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Context, Result};
+use geometry::Point2;
+use input_parser::{FromPattern, Sections};
+use recorder::{Frame, Recorder};
+use solution::Solution;
+
+#[derive(FromPattern)]
+pub enum Fold {
+    #[pattern("fold along x={0}")]
+    X(i64),
+    #[pattern("fold along y={0}")]
+    Y(i64),
+}
+
+/// Mirrors points to the right of the fold line onto its left.
+fn fold_x(p: &Point2, coordinate: i64) -> Point2 {
+    if p.x < coordinate {
+        *p
+    } else if p.x > coordinate {
+        Point2::new(2 * coordinate - p.x, p.y)
+    } else {
+        panic!("point on fold line");
+    }
+}
+
+/// Mirrors points below the fold line onto its upper side.
+fn fold_y(p: &Point2, coordinate: i64) -> Point2 {
+    if p.y < coordinate {
+        *p
+    } else if p.y > coordinate {
+        Point2::new(p.x, 2 * coordinate - p.y)
+    } else {
+        panic!("point on fold line");
+    }
+}
+
+fn fold(points: &HashSet<Point2>, fold: &Fold) -> HashSet<Point2> {
+    match fold {
+        Fold::X(coordinate) => points.iter().map(|p| fold_x(p, *coordinate)).collect(),
+        Fold::Y(coordinate) => points.iter().map(|p| fold_y(p, *coordinate)).collect(),
+    }
+}
+
+fn frame(points: &HashSet<Point2>) -> Frame {
+    Frame::from_points(points.iter().map(|p| (p.x, p.y)))
+}
+
+/// Draws the points with `#` on a background of `.`, one line per row.
+fn render(points: &HashSet<Point2>) -> String {
+    frame(points).to_string()
+}
+
+pub struct Manual {
+    points: HashSet<Point2>,
+    folds: Vec<Fold>,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Transparent Origami";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Manual;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut sections = Sections::from(input);
+        let points = sections.records::<Point2>()?.into_iter().collect();
+        let folds = sections.records()?;
+        sections
+            .finish()
+            .context("unexpected input format (expected \"<points>\\n\\n<folds>\")")?;
+        Ok(Manual { points, folds })
+    }
+
+    fn part1(manual: &Self::Input) -> Result<usize> {
+        let first = manual
+            .folds
+            .first()
+            .ok_or_else(|| anyhow!("no folds given"))?;
+        Ok(fold(&manual.points, first).len())
+    }
+
+    fn part2(manual: &Self::Input) -> Result<String> {
+        let folded = manual
+            .folds
+            .iter()
+            .fold(manual.points.clone(), |points, f| fold(&points, f));
+        Ok(render(&folded))
+    }
+
+    fn visualize(manual: &Self::Input, recorder: &mut Recorder) -> Result<()> {
+        recorder.record(|| frame(&manual.points));
+        manual
+            .folds
+            .iter()
+            .fold(manual.points.clone(), |points, f| {
+                let folded = fold(&points, f);
+                recorder.record(|| frame(&folded));
+                folded
+            });
+        Ok(())
+    }
+
+    fn input_stats(manual: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![
+            ("points", manual.points.len() as i64),
+            ("folds", manual.folds.len() as i64),
+        ]
+    }
+}
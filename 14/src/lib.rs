@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use input_parser::{FromPattern, PatternError, Sections};
+use solution::Solution;
+use thiserror::Error;
+
+#[derive(Clone, Debug)]
+pub struct Polymer {
+    pairs: HashMap<(char, char), u128>, // Count available pairs in the polymer
+    elements: HashMap<char, u128>,      // Count elements in the polymer --> for scoring
+}
+
+type ElementPair = (char, char);
+
+#[derive(FromPattern)]
+#[pattern("{pair} -> {insert}")]
+struct Rule {
+    pair: String,
+    insert: char,
+}
+
+#[derive(Error, Debug)]
+enum ParseRuleError {
+    #[error("invalid rule format: {0}")]
+    Pattern(#[from] PatternError),
+    #[error("invalid rule format (expected a pair of elements, got {0})")]
+    UnexpectedPair(String),
+}
+
+fn rule_from_str(s: &str) -> Result<(ElementPair, (ElementPair, ElementPair)), ParseRuleError> {
+    let Rule { pair, insert } = s.parse()?;
+    let match_pair = match pair.chars().collect::<Vec<_>>()[..] {
+        [c1, c2] => (c1, c2),
+        _ => return Err(ParseRuleError::UnexpectedPair(pair)),
+    };
+    let new_pairs = ((match_pair.0, insert), (insert, match_pair.1));
+    Ok((match_pair, new_pairs))
+}
+
+fn polymerization_step(
+    polymer: &mut Polymer,
+    rules: &HashMap<ElementPair, (ElementPair, ElementPair)>,
+) {
+    let old_template = polymer.pairs.clone();
+    old_template.iter().for_each(|(k, v)| {
+        if let Some((p1, p2)) = rules.get(k) {
+            *polymer.pairs.entry(*p1).or_insert(0) += v;
+            *polymer.pairs.entry(*p2).or_insert(0) += v;
+            *polymer.elements.entry(p1.1).or_insert(0) += v;
+            *polymer.pairs.entry(*k).or_insert(0) -= v;
+        }
+    })
+}
+
+fn polymerization(
+    polymer: &mut Polymer,
+    rules: &HashMap<ElementPair, (ElementPair, ElementPair)>,
+    steps: u32,
+) {
+    for _ in 0..steps {
+        polymerization_step(polymer, rules);
+    }
+}
+
+fn polymer_score(polymer: &Polymer) -> u128 {
+    polymer.elements.values().max().expect("empty polymer")
+        - polymer
+            .elements
+            .values()
+            .filter(|&v| *v > 0)
+            .min()
+            .expect("empty polymer")
+}
+
+pub struct Instructions {
+    template: Polymer,
+    rules: HashMap<ElementPair, (ElementPair, ElementPair)>,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Instructions;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut sections = Sections::from(input);
+
+        let template: String = sections.header()?;
+
+        let pairs = template.chars().zip(template.chars().skip(1)).fold(
+            HashMap::new(),
+            |mut acc, (c1, c2)| {
+                *acc.entry((c1, c2)).or_insert(0) += 1;
+                acc
+            },
+        );
+        let elements = template.chars().fold(HashMap::new(), |mut acc, c| {
+            *acc.entry(c).or_insert(0) += 1;
+            acc
+        });
+
+        let rules = sections
+            .next_section()?
+            .parse_with(rule_from_str)?
+            .into_iter()
+            .collect();
+        sections.finish()?;
+
+        Ok(Instructions {
+            template: Polymer { pairs, elements },
+            rules,
+        })
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<u128> {
+        let mut polymer = instructions.template.clone();
+        polymerization(&mut polymer, &instructions.rules, 10);
+        Ok(polymer_score(&polymer))
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<u128> {
+        let mut polymer = instructions.template.clone();
+        polymerization(&mut polymer, &instructions.rules, 40);
+        Ok(polymer_score(&polymer))
+    }
+
+    fn input_stats(instructions: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("rules", instructions.rules.len() as i64)]
+    }
+}
@@ -0,0 +1,112 @@
+use anyhow::{anyhow, Result};
+use input_parser::Grid;
+use search::SearchProblem;
+use solution::Solution;
+
+type Index = (usize, usize);
+
+#[derive(Debug)]
+struct Cave {
+    risk_levels: Grid<u8>,
+}
+
+impl Cave {
+    fn from(risk_levels: &Grid<u8>, expansion_factor: usize) -> Self {
+        let (height, width) = (risk_levels.height(), risk_levels.width());
+        let risk_levels = Grid::from_fn(
+            height * expansion_factor,
+            width * expansion_factor,
+            |i, j| {
+                let risk_incr = (i / height + j / width) as u8;
+                (risk_levels[(i % height, j % width)] - 1 + risk_incr) % 9 + 1
+            },
+        );
+        Cave { risk_levels }
+    }
+
+    fn get_neighbors(&self, node: Index) -> Vec<(Index, u8)> {
+        self.risk_levels
+            .neighbors4((node.0 as isize, node.1 as isize))
+            .map(|((i, j), &r)| ((i as usize, j as usize), r))
+            .collect()
+    }
+}
+
+/// Finding the lowest total risk from the top left to the bottom right corner of a cave.
+struct RiskSearch<'a> {
+    cave: &'a Cave,
+    from: Index,
+    to: Index,
+}
+
+impl SearchProblem for RiskSearch<'_> {
+    type State = Index;
+    type Cost = u32;
+
+    fn start(&self) -> Index {
+        self.from
+    }
+
+    fn successors(&self, node: &Index) -> Vec<(Index, u32)> {
+        self.cave
+            .get_neighbors(*node)
+            .into_iter()
+            .map(|(next, risk)| (next, risk as u32))
+            .collect()
+    }
+
+    fn is_goal(&self, node: &Index) -> bool {
+        *node == self.to
+    }
+
+    /// Every position costs at least 1, so the Manhattan distance never overestimates
+    fn heuristic(&self, node: &Index) -> u32 {
+        (node.0.abs_diff(self.to.0) + node.1.abs_diff(self.to.1)) as u32
+    }
+}
+
+fn lowest_risk_cost(cave: &Cave, from: Index, to: Index) -> Option<u32> {
+    search::astar(&RiskSearch { cave, from, to })
+        .path
+        .map(|p| p.cost)
+}
+
+fn find_path(inputs: &Grid<u8>, expansion_factor: usize) -> Option<u32> {
+    let cave = Cave::from(inputs, expansion_factor);
+    lowest_risk_cost(
+        &cave,
+        (0, 0),
+        (cave.risk_levels.height() - 1, cave.risk_levels.width() - 1),
+    )
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Chiton";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Grid<u8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(risk_levels: &Self::Input) -> Result<u32> {
+        find_path(risk_levels, 1).ok_or_else(|| anyhow!("no path found"))
+    }
+
+    fn part2(risk_levels: &Self::Input) -> Result<u32> {
+        find_path(risk_levels, 5).ok_or_else(|| anyhow!("no path found"))
+    }
+
+    fn input_stats(risk_levels: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![
+            ("height", risk_levels.height() as i64),
+            ("width", risk_levels.width() as i64),
+        ]
+    }
+}
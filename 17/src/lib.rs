@@ -0,0 +1,159 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use solution::Solution;
+
+#[derive(Debug)]
+struct State {
+    x: u32,
+    y: i32,
+    v_x: u32,
+    v_y: i32,
+}
+
+#[derive(Debug)]
+pub struct Target {
+    x: (u32, u32),
+    y: (i32, i32),
+}
+
+#[derive(Debug)]
+pub enum ParseTargetError {
+    UnexpectedFormat(String),
+    ParseCoordinate(ParseIntError),
+}
+
+impl Display for ParseTargetError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::UnexpectedFormat(s) => write!(
+                f,
+                "invalid target format (expected \"target area: x=<min>..<max>, y=<min>..<max>\", got {})",
+                s
+            ),
+            Self::ParseCoordinate(e) => write!(f, "error parsing coordinate: {}", e),
+        }
+    }
+}
+
+impl Error for ParseTargetError {}
+
+impl From<ParseIntError> for ParseTargetError {
+    fn from(e: ParseIntError) -> Self {
+        Self::ParseCoordinate(e)
+    }
+}
+
+fn parse_range<T: FromStr<Err = ParseIntError>>(s: &str) -> Result<(T, T), ParseTargetError> {
+    let (min, max) = s
+        .split_once("..")
+        .ok_or_else(|| ParseTargetError::UnexpectedFormat(String::from(s)))?;
+    Ok((min.parse()?, max.parse()?))
+}
+
+impl FromStr for Target {
+    type Err = ParseTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unexpected_format = || ParseTargetError::UnexpectedFormat(String::from(s));
+        let (x, y) = s
+            .trim()
+            .strip_prefix("target area: ")
+            .and_then(|ranges| ranges.split_once(", "))
+            .ok_or_else(unexpected_format)?;
+        let x = x.strip_prefix("x=").ok_or_else(unexpected_format)?;
+        let y = y.strip_prefix("y=").ok_or_else(unexpected_format)?;
+        Ok(Target {
+            x: parse_range(x)?,
+            y: parse_range(y)?,
+        })
+    }
+}
+
+fn step(s: &mut State) {
+    s.x += s.v_x;
+    s.y += s.v_y;
+
+    s.v_x = if s.v_x > 0 { s.v_x - 1 } else { s.v_x };
+    s.v_y -= 1;
+}
+
+fn hits_target(
+    target_x: (u32, u32),
+    target_y: (i32, i32),
+    initial_pos: (u32, i32),
+    initial_v: (u32, i32),
+) -> bool {
+    let mut s = State {
+        x: initial_pos.0,
+        y: initial_pos.1,
+        v_x: initial_v.0,
+        v_y: initial_v.1,
+    };
+    loop {
+        if s.x > target_x.1 || s.y < target_y.0 {
+            // Overshot
+            return false;
+        } else if s.x >= target_x.0 && s.y <= target_y.1 {
+            // In target
+            return true;
+        } else {
+            // Still undershooting so perform another step
+            step(&mut s);
+        }
+    }
+}
+
+fn find_solutions(target_x: (u32, u32), target_y: (i32, i32)) -> Vec<(u32, i32)> {
+    let mut solutions = Vec::new();
+    for v_x in 0..target_x.1 + 1 {
+        for v_y in target_y.0 - 1..-target_y.0 + 1 {
+            if hits_target(target_x, target_y, (0, 0), (v_x, v_y)) {
+                solutions.push((v_x, v_y))
+            }
+        }
+    }
+    solutions
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Trick Shot";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Target;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(target: &Self::Input) -> anyhow::Result<i32> {
+        let (y_min, _) = target.y;
+        // Since we can find an x such that horizontal movement stops within the target area, we only have to consider y here.
+        // As the process of rising and falling is symmetric we have to reach the start y coordinate (= 0) again when falling.
+        // We have reached maximum height while still hitting the target if we have a velocity of |y_min| after passing this point.
+        // Any higher point would result in a higher velocity at the starting location and thus overshoot the target
+        // As we know the final speed v, we can calculate the highest point as \sum_{i = 0}^v v - i = 1/2 * v * (v + 1)
+        Ok(y_min * (y_min + 1) / 2)
+    }
+
+    fn part2(target: &Self::Input) -> anyhow::Result<usize> {
+        // We assume x_min to be non-negative and y_min to be non-positive.
+        Ok(find_solutions(target.x, target.y).len())
+    }
+
+    fn input_stats(target: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![
+            ("x min", target.x.0 as i64),
+            ("x max", target.x.1 as i64),
+            ("y min", target.y.0 as i64),
+            ("y max", target.y.1 as i64),
+        ]
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use anyhow::{anyhow, bail, Context, Result};
+use geometry::{Point3, Rotation};
+use input_parser::{FromPattern, Sections};
+use solution::Solution;
+
+/// Number of beacons two scanners must both detect to be aligned
+const MIN_OVERLAP: usize = 12;
+/// Number of beacon pairs among the overlapping beacons, which also share their distances
+const MIN_SHARED_DISTANCES: usize = MIN_OVERLAP * (MIN_OVERLAP - 1) / 2;
+
+#[derive(FromPattern)]
+#[pattern("--- scanner {0} ---")]
+struct ScannerHeader(usize);
+
+#[derive(Clone, Debug)]
+pub struct Scanner {
+    pub id: usize,
+    /// Beacon positions relative to the scanner, in its own orientation
+    pub beacons: Vec<Point3>,
+}
+
+impl Scanner {
+    /// Squared distances between all pairs of beacons with their multiplicities.
+    ///
+    /// They do not depend on the position and orientation of the scanner, so scanners that
+    /// overlap share many of them.
+    fn fingerprint(&self) -> HashMap<i64, usize> {
+        let mut distances = HashMap::new();
+        for (idx, a) in self.beacons.iter().enumerate() {
+            for b in &self.beacons[idx + 1..] {
+                let d = *a - *b;
+                *distances
+                    .entry(d.x * d.x + d.y * d.y + d.z * d.z)
+                    .or_insert(0) += 1;
+            }
+        }
+        distances
+    }
+}
+
+fn shared_distances(a: &HashMap<i64, usize>, b: &HashMap<i64, usize>) -> usize {
+    a.iter()
+        .map(|(d, &count)| count.min(b.get(d).copied().unwrap_or(0)))
+        .sum()
+}
+
+/// Position and orientation of a scanner relative to scanner 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pose {
+    pub rotation: Rotation,
+    /// Position of the scanner
+    pub translation: Point3,
+}
+
+impl Pose {
+    pub const IDENTITY: Pose = Pose {
+        rotation: Rotation::IDENTITY,
+        translation: Point3::ORIGIN,
+    };
+
+    /// Converts a position seen by the scanner into the coordinates of scanner 0.
+    pub fn apply(&self, p: Point3) -> Point3 {
+        self.rotation.apply(p) + self.translation
+    }
+}
+
+/// Finds the pose under which at least [`MIN_OVERLAP`] of `beacons` coincide with `known`.
+fn align(known: &[Point3], beacons: &[Point3]) -> Option<Pose> {
+    for rotation in Rotation::all() {
+        let rotated: Vec<_> = beacons.iter().map(|&b| rotation.apply(b)).collect();
+        let mut translations = HashMap::new();
+        for &k in known {
+            for &r in &rotated {
+                let count = translations.entry(k - r).or_insert(0);
+                *count += 1;
+                if *count >= MIN_OVERLAP {
+                    return Some(Pose {
+                        rotation,
+                        translation: k - r,
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Scanners that could not be aligned with any other scanner.
+#[derive(Debug)]
+pub struct UnplacedScanners(pub Vec<usize>);
+
+impl Display for UnplacedScanners {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let ids: Vec<_> = self.0.iter().map(|id| id.to_string()).collect();
+        write!(
+            f,
+            "could not place scanner(s) {}, which share fewer than {} beacons with the placed scanners",
+            ids.join(", "),
+            MIN_OVERLAP
+        )
+    }
+}
+
+impl Error for UnplacedScanners {}
+
+/// All scanners and beacons in the coordinates of scanner 0.
+#[derive(Clone, Debug)]
+pub struct ScannerMap {
+    /// Pose of every scanner, in the order of the input
+    pub poses: Vec<Pose>,
+    pub beacons: HashSet<Point3>,
+}
+
+impl ScannerMap {
+    /// Places the scanners one after the other next to already placed scanners they overlap with.
+    ///
+    /// Only pairs whose fingerprints share enough distances are tried in all 24 orientations.
+    pub fn assemble(scanners: &[Scanner]) -> Result<Self, UnplacedScanners> {
+        let fingerprints: Vec<_> = scanners.iter().map(Scanner::fingerprint).collect();
+        let mut poses: Vec<Option<Pose>> = vec![None; scanners.len()];
+        // Beacons of the placed scanners in the coordinates of scanner 0
+        let mut placed: Vec<Vec<Point3>> = vec![Vec::new(); scanners.len()];
+        let mut queue = Vec::new();
+        if let Some(first) = scanners.first() {
+            poses[0] = Some(Pose::IDENTITY);
+            placed[0] = first.beacons.clone();
+            queue.push(0);
+        }
+        while let Some(known) = queue.pop() {
+            for (idx, scanner) in scanners.iter().enumerate() {
+                if poses[idx].is_some()
+                    || shared_distances(&fingerprints[known], &fingerprints[idx])
+                        < MIN_SHARED_DISTANCES
+                {
+                    continue;
+                }
+                if let Some(pose) = align(&placed[known], &scanner.beacons) {
+                    poses[idx] = Some(pose);
+                    placed[idx] = scanner.beacons.iter().map(|&b| pose.apply(b)).collect();
+                    queue.push(idx);
+                }
+            }
+        }
+
+        let unplaced: Vec<_> = scanners
+            .iter()
+            .zip(&poses)
+            .filter(|(_, pose)| pose.is_none())
+            .map(|(scanner, _)| scanner.id)
+            .collect();
+        if !unplaced.is_empty() {
+            return Err(UnplacedScanners(unplaced));
+        }
+        Ok(ScannerMap {
+            poses: poses.into_iter().flatten().collect(),
+            beacons: placed.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Largest Manhattan distance between any two scanners.
+    pub fn max_scanner_distance(&self) -> u64 {
+        self.poses
+            .iter()
+            .flat_map(|a| self.poses.iter().map(move |b| (a, b)))
+            .map(|(a, b)| a.translation.manhattan(&b.translation))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Beacon Scanner";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Vec<Scanner>;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Sections::from(input)
+            .map(|section| {
+                let (header, beacons) = section
+                    .lines()
+                    .split_first()
+                    .ok_or_else(|| anyhow!("empty section"))?;
+                let ScannerHeader(id) = header.parse().with_context(|| {
+                    format!(
+                        "line {}: expected \"--- scanner <n> ---\"",
+                        section.first_line
+                    )
+                })?;
+                if id != section.index {
+                    bail!(
+                        "line {}: expected scanner {}, found scanner {}",
+                        section.first_line,
+                        section.index,
+                        id
+                    );
+                }
+                let beacons = beacons
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, line)| {
+                        line.parse().with_context(|| {
+                            format!("line {}: invalid beacon", section.first_line + idx + 1)
+                        })
+                    })
+                    .collect::<Result<_>>()?;
+                Ok(Scanner { id, beacons })
+            })
+            .collect()
+    }
+
+    fn part1(scanners: &Self::Input) -> Result<usize> {
+        Ok(ScannerMap::assemble(scanners)?.beacons.len())
+    }
+
+    fn part2(scanners: &Self::Input) -> Result<u64> {
+        Ok(ScannerMap::assemble(scanners)?.max_scanner_distance())
+    }
+
+    fn input_stats(scanners: &Self::Input) -> Vec<(&'static str, i64)> {
+        let beacons: usize = scanners.iter().map(|s| s.beacons.len()).sum();
+        vec![
+            ("scanners", scanners.len() as i64),
+            ("beacons", beacons as i64),
+        ]
+    }
+}
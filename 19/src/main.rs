@@ -0,0 +1,5 @@
+use day19::Day19;
+
+fn main() -> anyhow::Result<()> {
+    solution::main::<Day19>()
+}
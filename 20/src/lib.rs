@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+use geometry::Point2;
+use input_parser::{Grid, Sections};
+use solution::Solution;
+
+/// Maps each 3x3 neighborhood, read row by row as a 9-bit number, to the enhanced pixel.
+#[derive(Clone, Debug)]
+pub struct Algorithm(Vec<bool>);
+
+impl Algorithm {
+    pub const LEN: usize = 512;
+
+    pub fn new(bits: Vec<bool>) -> Result<Self> {
+        if bits.len() != Self::LEN {
+            bail!(
+                "algorithm has {} entries instead of {}",
+                bits.len(),
+                Self::LEN
+            );
+        }
+        Ok(Algorithm(bits))
+    }
+
+    fn lookup(&self, index: usize) -> bool {
+        self.0[index]
+    }
+}
+
+/// An infinite image whose pixels all have the background value, except for finitely many.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    /// Pixels whose value differs from the background
+    pixels: HashSet<Point2>,
+    /// Whether the pixels not in `pixels` are lit
+    background: bool,
+}
+
+impl Image {
+    /// Image with the lit pixels of `grid` on an unlit background, row `i` and column `j`
+    /// become the point `(j, i)`.
+    pub fn from_grid(grid: &Grid<bool>) -> Self {
+        let pixels = grid
+            .iter()
+            .filter(|(_, &lit)| lit)
+            .map(|((i, j), _)| Point2::new(j as i64, i as i64))
+            .collect();
+        Image {
+            pixels,
+            background: false,
+        }
+    }
+
+    pub fn is_lit(&self, p: &Point2) -> bool {
+        self.pixels.contains(p) != self.background
+    }
+
+    /// Number of lit pixels, `None` if the lit background makes it infinite.
+    pub fn lit_count(&self) -> Option<usize> {
+        if self.background {
+            None
+        } else {
+            Some(self.pixels.len())
+        }
+    }
+
+    /// Applies the algorithm to every pixel at once.
+    ///
+    /// The background turns into the value of an all-background neighborhood, so only pixels
+    /// within one step of the finitely many exceptions can end up differing from it.
+    pub fn enhance(&self, algorithm: &Algorithm) -> Image {
+        let background = algorithm.lookup(if self.background {
+            Algorithm::LEN - 1
+        } else {
+            0
+        });
+        let candidates: HashSet<Point2> =
+            self.pixels.iter().flat_map(|&p| neighborhood(p)).collect();
+        let pixels = candidates
+            .into_iter()
+            .filter(|&p| {
+                let index = neighborhood(p).fold(0, |acc, n| 2 * acc + self.is_lit(&n) as usize);
+                algorithm.lookup(index) != background
+            })
+            .collect();
+        Image { pixels, background }
+    }
+}
+
+/// The 3x3 block around `center`, row by row from the top left.
+fn neighborhood(center: Point2) -> impl Iterator<Item = Point2> {
+    (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| center + Point2::new(dx, dy)))
+}
+
+fn lit_after(algorithm: &Algorithm, image: &Image, steps: usize) -> Result<usize> {
+    let enhanced = (0..steps).fold(image.clone(), |image, _| image.enhance(algorithm));
+    match enhanced.lit_count() {
+        Some(count) => Ok(count),
+        None => bail!("infinitely many pixels are lit after {} steps", steps),
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Trench Map";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = (Algorithm, Image);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut sections = Sections::from(input);
+        let algorithm = sections.next_section()?;
+        let algorithm: Grid<bool> = Grid::parse(&algorithm.lines().concat())
+            .context("algorithm must consist of '#' and '.'")?;
+        let algorithm = Algorithm::new(algorithm.iter().map(|(_, &lit)| lit).collect())?;
+        let image = sections.next_section()?;
+        let image = Grid::parse(&image.lines().join("\n")).context("invalid image")?;
+        sections
+            .finish()
+            .context("unexpected input format (expected \"<algorithm>\\n\\n<image>\")")?;
+        Ok((algorithm, Image::from_grid(&image)))
+    }
+
+    fn part1((algorithm, image): &Self::Input) -> Result<usize> {
+        lit_after(algorithm, image, 2)
+    }
+
+    fn part2((algorithm, image): &Self::Input) -> Result<usize> {
+        lit_after(algorithm, image, 50)
+    }
+
+    fn input_stats((_, image): &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("lit pixels", image.pixels.len() as i64)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day20::parse(include_str!("../example")).unwrap();
+        assert_eq!(Day20::part1(&input).unwrap(), 35);
+        assert_eq!(Day20::part2(&input).unwrap(), 3351);
+    }
+
+    #[test]
+    fn flipping_background() {
+        // Inverts every pixel, so an unlit background turns lit and back again
+        let algorithm = Algorithm::new((0..Algorithm::LEN).map(|i| i & 1 << 4 == 0).collect());
+        let algorithm = algorithm.unwrap();
+        let (_, image) = Day20::parse(include_str!("../example")).unwrap();
+        let lit = image.lit_count().unwrap();
+        let once = image.enhance(&algorithm);
+        assert_eq!(once.lit_count(), None);
+        assert_eq!(
+            once.enhance(&algorithm).enhance(&algorithm).lit_count(),
+            None
+        );
+        let twice = once.enhance(&algorithm);
+        assert_eq!(twice.lit_count(), Some(lit));
+        assert_eq!(twice, image);
+        assert!(lit_after(&algorithm, &image, 3).is_err());
+        assert_eq!(lit_after(&algorithm, &image, 4).unwrap(), lit);
+    }
+}
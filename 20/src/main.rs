@@ -0,0 +1,5 @@
+use day20::Day20;
+
+fn main() -> anyhow::Result<()> {
+    solution::main::<Day20>()
+}
@@ -0,0 +1,235 @@
+use std::cmp::{max, min};
+
+use anyhow::{bail, Result};
+use input_parser::FromPattern;
+use itertools::iproduct;
+use memo::{memoize, Memo};
+use solution::Solution;
+
+#[derive(Debug, FromPattern)]
+#[pattern("Player {player} starting position: {pos}")]
+struct StartingPosition {
+    player: u32,
+    pos: u32,
+}
+
+struct DeterministicDie {
+    gen: Box<dyn Iterator<Item = u32>>,
+    rolls: u32,
+}
+
+impl DeterministicDie {
+    fn new() -> Self {
+        DeterministicDie {
+            gen: Box::new((0..).map(|i| i % 100 + 1)),
+            rolls: 0,
+        }
+    }
+
+    fn next(&mut self) -> u32 {
+        self.rolls += 1;
+        self.gen.next().expect("die has run out of values")
+    }
+}
+
+fn roll(die: &mut DeterministicDie) -> u32 {
+    die.next() + die.next() + die.next()
+}
+
+fn practice_game(p1_start: u32, p2_start: u32) -> (u32, u32, u32) {
+    let mut die = DeterministicDie::new();
+    let mut p1 = (p1_start - 1, 0);
+    let mut p2 = (p2_start - 1, 0);
+    let mut p1_turn = true;
+    while p1.1 < 1000 && p2.1 < 1000 {
+        let roll = roll(&mut die);
+        if p1_turn {
+            p1.0 += roll;
+            p1.0 %= 10;
+            p1.1 += p1.0 + 1
+        } else {
+            p2.0 += roll;
+            p2.0 %= 10;
+            p2.1 += p2.0 + 1
+        }
+        p1_turn = !p1_turn;
+    }
+    (p1.1, p2.1, die.rolls)
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+struct State {
+    pos: u32,
+    score: u32,
+}
+
+struct DiracPredictor {
+    mins: Memo<State, u32>,
+    maxs: Memo<State, u32>,
+    wins: Memo<(State, State), (u128, u128)>,
+    wins_from: Memo<State, u128>,
+}
+
+impl DiracPredictor {
+    fn new() -> Self {
+        DiracPredictor {
+            mins: Memo::new("min turns"),
+            maxs: Memo::new("max turns"),
+            wins: Memo::new("wins"),
+            wins_from: Memo::new("wins from"),
+        }
+    }
+
+    fn min_turns_to_win(&mut self, state: &State) -> u32 {
+        if state.score >= 21 {
+            return 0;
+        }
+        memoize!(
+            self.mins,
+            *state,
+            Self::turn(state)
+                .iter()
+                .map(|s| self.min_turns_to_win(s))
+                .min()
+                .unwrap()
+                + 1
+        )
+    }
+
+    fn max_turns_to_win(&mut self, state: &State) -> u32 {
+        if state.score >= 21 {
+            return 0;
+        }
+        memoize!(
+            self.maxs,
+            *state,
+            Self::turn(state)
+                .iter()
+                .map(|s| self.max_turns_to_win(s))
+                .max()
+                .unwrap()
+                + 1
+        )
+    }
+
+    fn turn(state: &State) -> Vec<State> {
+        iproduct!(1..=3, 1..=3, 1..=3)
+            .map(|(i, j, k)| i + j + k)
+            .map(|roll| {
+                let pos = (state.pos - 1 + roll) % 10 + 1;
+                let score = state.score + pos;
+                State { pos, score }
+            })
+            .collect()
+    }
+
+    fn wins(&mut self, p1: &State, p2: &State) -> (u128, u128) {
+        memoize!(self.wins, (*p1, *p2), {
+            let p1_max = self.max_turns_to_win(p1);
+            let p2_min = self.min_turns_to_win(p2);
+            if p1_max <= p2_min {
+                // P1 wins, since they go first
+                (self.wins_from(p1), 0)
+            } else {
+                // Winner unknown --> let the current player P1 take a turn and flip P1 and P2 for the next step
+                DiracPredictor::turn(p1)
+                    .iter()
+                    .map(|p1_next| {
+                        let max_turns = self.max_turns_to_win(p1_next);
+                        let min_turns = self.min_turns_to_win(p1_next);
+                        if min_turns == max_turns && min_turns == 0 {
+                            // P1 wins now, before P2 gets to create universes
+                            (0, 1)
+                        } else {
+                            self.wins(p2, p1_next)
+                        }
+                    })
+                    .fold((0, 0), |acc, w| (acc.0 + w.1, acc.1 + w.0))
+            }
+        })
+    }
+
+    fn wins_from(&mut self, state: &State) -> u128 {
+        memoize!(self.wins_from, *state, {
+            let max_turns = self.max_turns_to_win(state);
+            let min_turns = self.min_turns_to_win(state);
+            if min_turns == max_turns {
+                if min_turns == 0 {
+                    1
+                } else {
+                    let turns = 2 * min_turns - 1;
+                    27_u128.pow(turns)
+                }
+            } else {
+                DiracPredictor::turn(state)
+                    .iter()
+                    .map(|s| {
+                        let max_turns = self.max_turns_to_win(s);
+                        let min_turns = self.min_turns_to_win(s);
+                        if min_turns == max_turns && min_turns == 0 {
+                            // P1 wins now, before P2 gets to create universes
+                            1
+                        } else {
+                            27 * self.wins_from(s)
+                        }
+                    })
+                    .sum::<u128>()
+            }
+        })
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Dirac Dice";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = (u32, u32);
+    type Answer1 = u32;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let players: Vec<StartingPosition> = input_parser::parse_str(input)?;
+        if players.len() != 2 || players[0].player != 1 || players[1].player != 2 {
+            bail!("expected starting positions for player 1 and 2")
+        }
+        for player in &players {
+            if !(1..=10).contains(&player.pos) {
+                bail!(
+                    "starting position {} of player {} is not on the board (1 to 10)",
+                    player.pos,
+                    player.player
+                )
+            }
+        }
+        Ok((players[0].pos, players[1].pos))
+    }
+
+    fn part1(&(p1_start, p2_start): &Self::Input) -> Result<u32> {
+        let (p1, p2, rolls) = practice_game(p1_start, p2_start);
+        Ok(min(p1, p2) * rolls)
+    }
+
+    fn part2(&(p1_start, p2_start): &Self::Input) -> Result<u128> {
+        let p1 = State {
+            pos: p1_start,
+            score: 0,
+        };
+        let p2 = State {
+            pos: p2_start,
+            score: 0,
+        };
+        let mut pred = DiracPredictor::new();
+        let wins = pred.wins(&p1, &p2);
+        Ok(max(wins.0, wins.1))
+    }
+
+    fn input_stats(&(p1_start, p2_start): &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![
+            ("player 1 start", p1_start as i64),
+            ("player 2 start", p2_start as i64),
+        ]
+    }
+}
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use geometry::{Box3, Point3};
+use solution::Solution;
+
+peg::parser! {
+    grammar reboot_parser() for str {
+        rule number() -> i64
+            = n:$("-"?['0'..='9']+) {? n.parse().or(Err("i64")) }
+
+        rule range() -> (i64, i64)
+            = min:number() ".." max:number() { (min, max) }
+
+        rule cuboid() -> Box3
+            = "x=" x:range() ",y=" y:range() ",z=" z:range() {
+                Box3::from_inclusive(Point3::new(x.0, y.0, z.0), Point3::new(x.1, y.1, z.1))
+            }
+
+        rule command() -> Command
+            = c:$("on" / "off") {? match c {
+                "on" => Ok(Command::On),
+                "off" => Ok(Command::Off),
+                c => Err("command")
+            } }
+
+        rule reboot_step() -> RebootStep
+            = command:command() " " area:cuboid() { RebootStep{ command, area } }
+
+        pub rule reboot_sequence() -> Vec<RebootStep>
+            = sequence:reboot_step() ** "\n" { sequence }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Command {
+    On,
+    Off,
+}
+
+/// Splits the cells of `cuboid` that are in none of `others` into disjoint cuboids.
+fn without_all(cuboid: &Box3, others: &[Box3]) -> Vec<Box3> {
+    others.iter().fold(vec![*cuboid], |acc, o| {
+        acc.iter().flat_map(|c| c.difference(o)).collect()
+    })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RebootStep {
+    area: Box3,
+    command: Command,
+}
+
+fn reboot(reboot_sequence: &[RebootStep]) -> u128 {
+    let mut on: Vec<Box3> = Vec::new();
+    for step in reboot_sequence {
+        match step.command {
+            Command::On => on.extend(without_all(&step.area, &on)),
+            Command::Off => on = on.iter().flat_map(|c| c.difference(&step.area)).collect(),
+        }
+    }
+    on.iter().map(|c| c.volume()).sum::<u128>()
+}
+
+fn initialization_sequence(reboot_sequence: &[RebootStep]) -> u128 {
+    let init_region = Box3::from_inclusive(Point3::new(-50, -50, -50), Point3::new(50, 50, 50));
+    reboot(
+        reboot_sequence
+            .iter()
+            .filter(|s| init_region.encloses(&s.area))
+            .copied()
+            .collect::<Vec<_>>()
+            .as_slice(),
+    )
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Reactor Reboot";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Vec<RebootStep>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(reboot_parser::reboot_sequence(input.trim())?)
+    }
+
+    fn part1(reboot_sequence: &Self::Input) -> Result<u128> {
+        Ok(initialization_sequence(reboot_sequence))
+    }
+
+    fn part2(reboot_sequence: &Self::Input) -> Result<u128> {
+        Ok(reboot(reboot_sequence))
+    }
+
+    fn input_stats(steps: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("steps", steps.len() as i64)]
+    }
+}
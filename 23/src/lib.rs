@@ -0,0 +1,528 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use anyhow::{anyhow, Result};
+use recorder::{Frame, Recorder};
+use search::SearchProblem;
+use solution::Solution;
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    fn step_cost(&self) -> u32 {
+        match self {
+            Self::Amber => 1,
+            Self::Bronze => 10,
+            Self::Copper => 100,
+            Self::Desert => 1000,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum ParseBurrowError {
+    UnknownAmphipod(char),
+    UnexpectedFormat(String),
+}
+
+impl Display for ParseBurrowError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownAmphipod(c) => write!(f, "unknown amphipod {:?}", c),
+            Self::UnexpectedFormat(s) => write!(f, "unexpected burrow format: {:?}", s),
+        }
+    }
+}
+
+impl Error for ParseBurrowError {}
+
+/// Amphipods of the folded diagram, each room from top to bottom
+pub struct Burrow {
+    rooms: [[Amphipod; 2]; 4],
+}
+
+impl Amphipod {
+    fn from_char(c: char) -> std::result::Result<Self, ParseBurrowError> {
+        match c {
+            'A' => Ok(Self::Amber),
+            'B' => Ok(Self::Bronze),
+            'C' => Ok(Self::Copper),
+            'D' => Ok(Self::Desert),
+            c => Err(ParseBurrowError::UnknownAmphipod(c)),
+        }
+    }
+}
+
+impl Display for Amphipod {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Amber => "A",
+            Self::Bronze => "B",
+            Self::Copper => "C",
+            Self::Desert => "D",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum Tile {
+    Occupied(Amphipod),
+    Free,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Occupied(a) => write!(f, "{}", a),
+            Self::Free => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct State {
+    hallway: [Tile; 11],
+    rooms: HashMap<Amphipod, (usize, [Tile; 4])>,
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for t in &self.hallway {
+            write!(f, "{}", t)?
+        }
+        write!(f, "\n  ")?;
+        let mut rooms: Vec<_> = self.rooms.values().collect();
+        rooms.sort_by_key(|a| a.0);
+        for (_, r) in &rooms {
+            write!(f, "{} ", r[0])?
+        }
+        write!(f, "\n  ")?;
+        for (_, r) in &rooms {
+            write!(f, "{} ", r[1])?
+        }
+        write!(f, "\n  ")?;
+        for (_, r) in &rooms {
+            write!(f, "{} ", r[2])?
+        }
+        write!(f, "\n  ")?;
+        for (_, r) in &rooms {
+            write!(f, "{} ", r[3])?
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.hallway
+            .iter()
+            .zip(other.hallway.iter())
+            .all(|(&t1, &t2)| t1 == t2)
+            && self
+                .rooms
+                .iter()
+                .map(|(k, v)| (k, v, other.rooms.get(k)))
+                .all(|(_, v1, v2)| {
+                    if let Some(v2) = v2 {
+                        v1.0 == v2.0 && v1.1.iter().zip(v2.1.iter()).all(|(&t1, &t2)| t1 == t2)
+                    } else {
+                        false
+                    }
+                })
+    }
+}
+
+impl Eq for State {}
+
+impl Hash for State {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hallway.hash(state);
+        // Hash rooms in a fixed order, since the map iterates in arbitrary order
+        for a in &[
+            Amphipod::Amber,
+            Amphipod::Bronze,
+            Amphipod::Copper,
+            Amphipod::Desert,
+        ] {
+            self.rooms.get(a).hash(state);
+        }
+    }
+}
+
+impl State {
+    fn from(
+        amber_room: [Amphipod; 4],
+        bronze_room: [Amphipod; 4],
+        copper_room: [Amphipod; 4],
+        desert_room: [Amphipod; 4],
+    ) -> Self {
+        let mut rooms = HashMap::new();
+        rooms.insert(
+            Amphipod::Amber,
+            (
+                2,
+                [
+                    Tile::Occupied(amber_room[0]),
+                    Tile::Occupied(amber_room[1]),
+                    Tile::Occupied(amber_room[2]),
+                    Tile::Occupied(amber_room[3]),
+                ],
+            ),
+        );
+        rooms.insert(
+            Amphipod::Bronze,
+            (
+                4,
+                [
+                    Tile::Occupied(bronze_room[0]),
+                    Tile::Occupied(bronze_room[1]),
+                    Tile::Occupied(bronze_room[2]),
+                    Tile::Occupied(bronze_room[3]),
+                ],
+            ),
+        );
+        rooms.insert(
+            Amphipod::Copper,
+            (
+                6,
+                [
+                    Tile::Occupied(copper_room[0]),
+                    Tile::Occupied(copper_room[1]),
+                    Tile::Occupied(copper_room[2]),
+                    Tile::Occupied(copper_room[3]),
+                ],
+            ),
+        );
+        rooms.insert(
+            Amphipod::Desert,
+            (
+                8,
+                [
+                    Tile::Occupied(desert_room[0]),
+                    Tile::Occupied(desert_room[1]),
+                    Tile::Occupied(desert_room[2]),
+                    Tile::Occupied(desert_room[3]),
+                ],
+            ),
+        );
+        State {
+            hallway: [Tile::Free; 11],
+            rooms,
+        }
+    }
+
+    fn next_states(&self) -> Vec<Move> {
+        let mut moves = Vec::new();
+        // Amphipods from hallway can move to their free destination room
+        for (pos, tile) in self.hallway.iter().enumerate() {
+            if let Tile::Occupied(a) = tile {
+                let (idx, _) = self.rooms.get(a).expect("missing room mapping");
+                if self.is_hallway_free(pos, *idx) {
+                    if let Some(room_idx) = self.is_room_free(*a) {
+                        let mut new_state = self.clone();
+                        new_state.hallway[pos] = Tile::Free;
+                        let (_, new_room) =
+                            new_state.rooms.get_mut(a).expect("missing room mapping");
+                        new_room[room_idx] = Tile::Occupied(*a);
+                        let moved_tiles =
+                            (pos as i32 - *idx as i32).unsigned_abs() + room_idx as u32 + 1;
+                        moves.push(Move {
+                            next: new_state,
+                            cost: moved_tiles * a.step_cost(),
+                        });
+                    }
+                }
+            }
+        }
+        // Amphipods can move from a room to the hallway
+        // Amphipods may never occupy hallway indices 2, 4, 6, and 8
+        for (room_type, (idx, room)) in &self.rooms {
+            for (room_idx, tile) in room.iter().enumerate() {
+                if let Tile::Occupied(a) = tile {
+                    if room[..room_idx].iter().all(|t| matches!(t, Tile::Free)) {
+                        // Only move out if we are in the wrong room or blocking others below us
+                        if a != room_type
+                            || room[room_idx + 1..]
+                                .iter()
+                                .any(|t| matches!(t, Tile::Occupied(other) if other != room_type))
+                        {
+                            for to in 0..11 {
+                                if to != 2
+                                    && to != 4
+                                    && to != 6
+                                    && to != 8
+                                    && self.is_hallway_free(*idx, to)
+                                {
+                                    let mut new_state = self.clone();
+                                    new_state.hallway[to] = Tile::Occupied(*a);
+                                    let (_, new_room) = new_state
+                                        .rooms
+                                        .get_mut(room_type)
+                                        .expect("missing room mapping");
+                                    new_room[room_idx] = Tile::Free;
+                                    let moved_tiles = (to as i32 - *idx as i32).unsigned_abs()
+                                        + room_idx as u32
+                                        + 1;
+                                    moves.push(Move {
+                                        next: new_state,
+                                        cost: moved_tiles * a.step_cost(),
+                                    });
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        moves
+    }
+
+    fn is_hallway_free(&self, from: usize, to: usize) -> bool {
+        if from > to {
+            self.hallway[to..from]
+                .iter()
+                .all(|t| matches!(t, Tile::Free))
+        } else {
+            self.hallway[from + 1..to + 1]
+                .iter()
+                .all(|t| matches!(t, Tile::Free))
+        }
+    }
+
+    fn is_room_free(&self, amphipod: Amphipod) -> Option<usize> {
+        let (_, room) = self.rooms.get(&amphipod).expect("missing room mapping");
+        room.iter()
+            .rev()
+            .position(|t| matches!(t, Tile::Free))
+            .map(|i| room.len() - 1 - i)
+            .and_then(|i| {
+                if room[i + 1..]
+                    .iter()
+                    .all(|t| matches!(t, Tile::Occupied(a) if *a == amphipod))
+                {
+                    Some(i)
+                } else {
+                    None
+                }
+            })
+    }
+
+    fn is_final(&self) -> bool {
+        self.rooms.iter().all(|(room_type, (_, room))| {
+            room.iter()
+                .all(|t| matches!(t, Tile::Occupied(a) if a == room_type))
+        })
+    }
+
+    fn estimate_cost(&self) -> u32 {
+        let room_cost: u32 = self
+            .rooms
+            .iter()
+            .map(|(room_type, (idx, room))| {
+                room.iter()
+                    .enumerate()
+                    .map(|(room_idx, t)| {
+                        if let Tile::Occupied(a) = t {
+                            if a != room_type
+                                || room[room_idx + 1..].iter().any(
+                                    |t| matches!(t, Tile::Occupied(other) if other != room_type),
+                                )
+                            {
+                                let (to, _) = self.rooms.get(a).expect("missing room mapping");
+                                // + 2 to move out and into room
+                                ((*to as i32 - *idx as i32).unsigned_abs() + 2) * a.step_cost()
+                            } else {
+                                0
+                            }
+                        } else {
+                            0
+                        }
+                    })
+                    .sum::<u32>()
+            })
+            .sum();
+        let hallway_cost: u32 = self
+            .hallway
+            .iter()
+            .enumerate()
+            .map(|(idx, t)| {
+                if let Tile::Occupied(a) = t {
+                    let (to, _) = self.rooms.get(a).expect("missing room mapping");
+                    // + 1 to move into room
+                    ((*to as i32 - idx as i32).unsigned_abs() + 1) * a.step_cost()
+                } else {
+                    0
+                }
+            })
+            .sum();
+        room_cost + hallway_cost
+    }
+}
+
+#[derive(Debug)]
+struct Move {
+    next: State,
+    cost: u32,
+}
+
+/// Sorting the amphipods into their rooms at the lowest energy cost.
+struct Sorting {
+    start: State,
+}
+
+impl SearchProblem for Sorting {
+    type State = State;
+    type Cost = u32;
+
+    fn start(&self) -> State {
+        self.start.clone()
+    }
+
+    fn successors(&self, state: &State) -> Vec<(State, u32)> {
+        state
+            .next_states()
+            .into_iter()
+            .map(|mov| (mov.next, mov.cost))
+            .collect()
+    }
+
+    fn is_goal(&self, state: &State) -> bool {
+        state.is_final()
+    }
+
+    fn heuristic(&self, state: &State) -> u32 {
+        state.estimate_cost()
+    }
+}
+
+fn lowest_cost_ordering(init: State) -> Option<search::Path<State, u32>> {
+    search::astar(&Sorting { start: init }).path
+}
+
+/// Parses the amphipods of the folded diagram, each room from top to bottom
+fn parse_burrow(s: &str) -> std::result::Result<[[Amphipod; 2]; 4], ParseBurrowError> {
+    let rows: Vec<_> = s
+        .lines()
+        .filter(|l| l.contains(|c: char| c.is_ascii_alphabetic()))
+        .collect();
+    if rows.len() != 2 {
+        return Err(ParseBurrowError::UnexpectedFormat(String::from(s)));
+    }
+    let mut rooms = [[Amphipod::Amber; 2]; 4];
+    for (depth, row) in rows.iter().enumerate() {
+        let amphipods = row
+            .chars()
+            .filter(|&c| c != '#' && c != ' ')
+            .map(Amphipod::from_char)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        if amphipods.len() != 4 {
+            return Err(ParseBurrowError::UnexpectedFormat(String::from(*row)));
+        }
+        for (room, a) in amphipods.into_iter().enumerate() {
+            rooms[room][depth] = a;
+        }
+    }
+    Ok(rooms)
+}
+
+/// Puts the folded diagram into rooms of depth four, whose bottom two tiles are already taken by
+/// amphipods of the room's own type. These never have to move, so the result equals the folded burrow.
+fn fill(rooms: [[Amphipod; 2]; 4]) -> State {
+    const OWN: [Amphipod; 4] = [
+        Amphipod::Amber,
+        Amphipod::Bronze,
+        Amphipod::Copper,
+        Amphipod::Desert,
+    ];
+    let room = |i: usize| [rooms[i][0], rooms[i][1], OWN[i], OWN[i]];
+    State::from(room(0), room(1), room(2), room(3))
+}
+
+/// Unfolds the diagram by inserting the two hidden rows between the parsed ones
+fn unfold(rooms: [[Amphipod; 2]; 4]) -> State {
+    const HIDDEN: [[Amphipod; 4]; 2] = [
+        [
+            Amphipod::Desert,
+            Amphipod::Copper,
+            Amphipod::Bronze,
+            Amphipod::Amber,
+        ],
+        [
+            Amphipod::Desert,
+            Amphipod::Bronze,
+            Amphipod::Amber,
+            Amphipod::Copper,
+        ],
+    ];
+    let room = |i: usize| [rooms[i][0], HIDDEN[0][i], HIDDEN[1][i], rooms[i][1]];
+    State::from(room(0), room(1), room(2), room(3))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Amphipod";
+    const INPUT: &'static str = include_str!("../input");
+
+    type Input = Burrow;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Burrow {
+            rooms: parse_burrow(input)?,
+        })
+    }
+
+    fn part1(burrow: &Self::Input) -> Result<u32> {
+        lowest_cost_ordering(fill(burrow.rooms))
+            .map(|path| path.cost)
+            .ok_or_else(|| anyhow!("found no ordering"))
+    }
+
+    fn part2(burrow: &Self::Input) -> Result<u32> {
+        lowest_cost_ordering(unfold(burrow.rooms))
+            .map(|path| path.cost)
+            .ok_or_else(|| anyhow!("found no ordering"))
+    }
+
+    /// Records the states of the cheapest way to sort the unfolded burrow
+    fn visualize(burrow: &Self::Input, recorder: &mut Recorder) -> Result<()> {
+        let path = lowest_cost_ordering(unfold(burrow.rooms))
+            .ok_or_else(|| anyhow!("found no ordering"))?;
+        for state in &path.states {
+            recorder.record(|| Frame::from_text(&state.to_string()));
+        }
+        Ok(())
+    }
+
+    fn input_stats(burrow: &Self::Input) -> Vec<(&'static str, i64)> {
+        // Rooms from left to right belong to amber, bronze, copper and desert amphipods
+        let owners = [
+            Amphipod::Amber,
+            Amphipod::Bronze,
+            Amphipod::Copper,
+            Amphipod::Desert,
+        ];
+        let misplaced = burrow
+            .rooms
+            .iter()
+            .zip(owners.iter())
+            .map(|(room, owner)| room.iter().filter(|&a| a != owner).count() as i64)
+            .sum();
+        vec![
+            ("rooms", burrow.rooms.len() as i64),
+            ("misplaced", misplaced),
+        ]
+    }
+}
@@ -0,0 +1,250 @@
+use std::convert::Infallible;
+use std::fmt::{self, Display};
+
+use anyhow::{anyhow, bail, Result};
+use input_parser::{Edges, Grid};
+use recorder::{Frame, Recorder};
+use solution::Solution;
+
+peg::parser! {
+    grammar map_parser() for str {
+        rule field() -> Option<SeaCucumber>
+            = "v" { Some(SeaCucumber::South) } / ">" { Some(SeaCucumber::East) } / "." { None }
+
+        rule row() -> Vec<Option<SeaCucumber>>
+            = field()*
+
+        pub rule map() -> Map
+            = rows:row() ** "\n" {?
+                Grid::from_rows(rows)
+                    .map(|grid| Map { grid: grid.with_edges(Edges::Wrapping) })
+                    .or(Err("different row lengths"))
+            }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum SeaCucumber {
+    East,
+    South,
+}
+
+impl Display for SeaCucumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::East => write!(f, ">"),
+            Self::South => write!(f, "v"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Map {
+    grid: Grid<Option<SeaCucumber>>,
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.grid.rows() {
+            writeln!(f)?;
+            for v in row {
+                match v {
+                    None => write!(f, ".")?,
+                    Some(c) => write!(f, "{}", c)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Map {
+    pub fn get(&self, i: isize, j: isize) -> Option<SeaCucumber> {
+        *self.grid.get((i, j)).expect("wrapping map has no edges")
+    }
+
+    pub fn set(&mut self, i: isize, j: isize, v: Option<SeaCucumber>) {
+        *self
+            .grid
+            .get_mut((i, j))
+            .expect("wrapping map has no edges") = v
+    }
+
+    pub fn iter_row(&self, i: usize) -> impl Iterator<Item = &Option<SeaCucumber>> {
+        self.grid.row(i).iter()
+    }
+
+    pub fn iter_col(&self, j: usize) -> impl Iterator<Item = &Option<SeaCucumber>> {
+        self.grid.col(j)
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_fn(self.width(), self.height(), |x, y| {
+            match self.grid.row(y)[x] {
+                None => '.',
+                Some(SeaCucumber::East) => '>',
+                Some(SeaCucumber::South) => 'v',
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Simulation {
+    map: Map,
+}
+
+impl Display for Simulation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+impl Simulation {
+    /// Number of the first step in which no sea cucumber moves, `None` if there is none within
+    /// one step per location of the map, after which the herds are assumed to move forever.
+    ///
+    /// Records the map before the first and after every step.
+    pub fn simulate_until_no_moves(&mut self, recorder: &mut Recorder) -> Option<u32> {
+        let max_steps = (self.map.height() * self.map.width()).max(1) as u32;
+        recorder.record(|| self.map.frame());
+        (1..=max_steps).find(|_| {
+            let moved = self.step();
+            recorder.record(|| self.map.frame());
+            !moved
+        })
+    }
+
+    pub fn step(&mut self) -> bool {
+        let mut changed = false;
+        changed |= self.move_east();
+        changed |= self.move_south();
+        changed
+    }
+
+    fn move_east(&mut self) -> bool {
+        let mut changed = false;
+        for i in 0..self.map.height() {
+            let does_move: Vec<_> = self
+                .map
+                .iter_row(i)
+                .zip(self.map.iter_row(i).skip(1).chain(self.map.iter_row(i)))
+                .map(|(c, n)| n.is_none() && matches!(c, Some(SeaCucumber::East)))
+                .collect();
+            for (j, does_move) in does_move.iter().enumerate() {
+                if *does_move {
+                    self.map.set(i as isize, j as isize, None);
+                    self.map
+                        .set(i as isize, j as isize + 1, Some(SeaCucumber::East));
+                }
+            }
+            changed |= does_move.iter().any(|&b| b);
+        }
+        changed
+    }
+
+    fn move_south(&mut self) -> bool {
+        let mut changed = false;
+        for j in 0..self.map.width() {
+            let does_move: Vec<_> = self
+                .map
+                .iter_col(j)
+                .zip(self.map.iter_col(j).skip(1).chain(self.map.iter_col(j)))
+                .map(|(c, n)| n.is_none() && matches!(c, Some(SeaCucumber::South)))
+                .collect();
+            for (i, does_move) in does_move.iter().enumerate() {
+                if *does_move {
+                    self.map.set(i as isize, j as isize, None);
+                    self.map
+                        .set(i as isize + 1, j as isize, Some(SeaCucumber::South));
+                }
+            }
+            changed |= does_move.iter().any(|&b| b);
+        }
+        changed
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Sea Cucumber";
+    const INPUT: &'static str = include_str!("../input");
+    const HAS_PART2: bool = false;
+
+    type Input = Map;
+    type Answer1 = u32;
+    type Answer2 = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(map_parser::map(input.trim())?)
+    }
+
+    fn part1(map: &Self::Input) -> Result<u32> {
+        let mut sim = Simulation { map: map.clone() };
+        sim.simulate_until_no_moves(&mut Recorder::disabled())
+            .ok_or_else(|| anyhow!("simulation never stops"))
+    }
+
+    fn part2(_map: &Self::Input) -> Result<Infallible> {
+        bail!("there is no second puzzle on the last day")
+    }
+
+    fn visualize(map: &Self::Input, recorder: &mut Recorder) -> Result<()> {
+        let mut sim = Simulation { map: map.clone() };
+        sim.simulate_until_no_moves(recorder)
+            .ok_or_else(|| anyhow!("simulation never stops"))?;
+        Ok(())
+    }
+
+    fn input_stats(map: &Self::Input) -> Vec<(&'static str, i64)> {
+        let herd = |member: fn(&Option<SeaCucumber>) -> bool| {
+            (0..map.height())
+                .flat_map(|i| map.iter_row(i))
+                .filter(|&c| member(c))
+                .count() as i64
+        };
+        let east = herd(|c| matches!(c, Some(SeaCucumber::East)));
+        let south = herd(|c| matches!(c, Some(SeaCucumber::South)));
+        vec![
+            ("height", map.height() as i64),
+            ("width", map.width() as i64),
+            ("east herd", east),
+            ("south herd", south),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steps(map: &str) -> Result<u32> {
+        Day25::part1(&Day25::parse(map)?)
+    }
+
+    #[test]
+    fn example() {
+        let example = include_str!("../example");
+        assert_eq!(steps(example).unwrap(), 58);
+    }
+
+    #[test]
+    fn never_stops() {
+        assert!(steps(">.\n").is_err());
+    }
+
+    #[test]
+    fn already_stuck() {
+        assert_eq!(steps(">>\nvv\n").unwrap(), 1);
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Context, Result};
+use solution::{Part, Runner};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part1",
+            Self::Part2 => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "parse" => Some(Self::Parse),
+            "part1" => Some(Self::Part1),
+            "part2" => Some(Self::Part2),
+            _ => None,
+        }
+    }
+}
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Self::Part1,
+            Part::Two => Self::Part2,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Wall time statistics of one phase over several runs.
+#[derive(Clone, Copy, Debug)]
+pub struct PhaseStats {
+    pub runs: usize,
+    pub min: Duration,
+    /// Upper median for an even number of runs
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl PhaseStats {
+    fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort_unstable();
+        Some(PhaseStats {
+            runs: samples.len(),
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// Benchmark results keyed by day and phase, as stored between runs.
+#[derive(Clone, Debug, Default)]
+pub struct BenchResults {
+    stats: BTreeMap<(u8, Phase), PhaseStats>,
+}
+
+impl BenchResults {
+    /// Loads the results of a previous run, a missing file counts as no previous results.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e).with_context(|| format!("could not read {}", path.display())),
+        };
+        let mut results = Self::default();
+        for (idx, line) in content.lines().enumerate() {
+            let parse_line = || -> Result<((u8, Phase), PhaseStats)> {
+                let fields: Vec<_> = line.split('\t').collect();
+                if fields.len() != 6 {
+                    bail!("expected 6 fields, got {}", fields.len());
+                }
+                let phase = Phase::from_name(fields[1])
+                    .ok_or_else(|| anyhow!("unknown phase {:?}", fields[1]))?;
+                let nanos = |s: &str| -> Result<Duration> { Ok(Duration::from_nanos(s.parse()?)) };
+                Ok((
+                    (fields[0].parse()?, phase),
+                    PhaseStats {
+                        runs: fields[5].parse()?,
+                        min: nanos(fields[2])?,
+                        median: nanos(fields[3])?,
+                        max: nanos(fields[4])?,
+                    },
+                ))
+            };
+            let (key, stats) = parse_line()
+                .with_context(|| format!("{}:{} is malformed", path.display(), idx + 1))?;
+            results.stats.insert(key, stats);
+        }
+        Ok(results)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content: String = self
+            .stats
+            .iter()
+            .map(|((day, phase), s)| {
+                format!(
+                    "{}\t{}\t{}\t{}\t{}\t{}\n",
+                    day,
+                    phase,
+                    s.min.as_nanos(),
+                    s.median.as_nanos(),
+                    s.max.as_nanos(),
+                    s.runs
+                )
+            })
+            .collect();
+        fs::write(path, content).with_context(|| format!("could not write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<&PhaseStats> {
+        self.stats.get(&(day, phase))
+    }
+
+    pub fn insert(&mut self, day: u8, phase: Phase, stats: PhaseStats) {
+        self.stats.insert((day, phase), stats);
+    }
+}
+
+/// Runs a solution up to `runs` times, but starts no further run once `budget` is used up.
+pub fn bench(
+    solution: &dyn Runner,
+    parts: &[Part],
+    runs: usize,
+    budget: Duration,
+) -> Result<Vec<(Phase, PhaseStats)>> {
+    let mut samples: BTreeMap<Phase, Vec<Duration>> = BTreeMap::new();
+    let start = Instant::now();
+    for _ in 0..runs {
+        let run = solution.run(solution.input(), parts)?;
+        samples
+            .entry(Phase::Parse)
+            .or_default()
+            .push(run.parse_time);
+        for part in run.parts {
+            samples.entry(part.part.into()).or_default().push(part.time);
+        }
+        if start.elapsed() > budget {
+            break;
+        }
+    }
+    Ok(samples
+        .into_iter()
+        .filter_map(|(phase, samples)| PhaseStats::from_samples(samples).map(|s| (phase, s)))
+        .collect())
+}
+
+/// Relative change of the median compared to a previous run.
+#[derive(Clone, Copy, Debug)]
+pub struct Change {
+    pub percent: f64,
+    pub regression: bool,
+}
+
+impl Change {
+    pub fn between(previous: &PhaseStats, current: &PhaseStats, threshold: f64) -> Self {
+        let previous = previous.median.as_secs_f64();
+        let current = current.median.as_secs_f64();
+        let percent = if previous > 0.0 {
+            (current - previous) / previous * 100.0
+        } else {
+            0.0
+        };
+        Change {
+            percent,
+            regression: percent > threshold,
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:+.1}%", self.percent)?;
+        if self.regression {
+            write!(f, " REGRESSION")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use serde_json::{json, Map, Value};
+use solution::{Run, Runner};
+
+/// Numeric answers become JSON numbers, everything else (e.g. rendered letters) stays a string.
+fn answer_value(answer: &str) -> Value {
+    if let Ok(n) = answer.parse::<i64>() {
+        Value::from(n)
+    } else if let Ok(n) = answer.parse::<u64>() {
+        Value::from(n)
+    } else {
+        Value::from(answer)
+    }
+}
+
+/// Describes the run of one day as a single JSON object.
+///
+/// A failed run is reported with its error chain in `diagnostics` instead of answers.
+pub fn day_report(solution: &dyn Runner, run: &anyhow::Result<Run>) -> Value {
+    let mut report = json!({
+        "day": solution.day(),
+        "title": solution.title(),
+        "ok": run.is_ok(),
+    });
+    match run {
+        Ok(run) => {
+            let stats: Map<String, Value> = run
+                .input_stats
+                .iter()
+                .map(|(name, count)| (String::from(*name), Value::from(*count)))
+                .collect();
+            report["input"] = json!({
+                "bytes": run.input_bytes,
+                "lines": run.input_lines,
+                "stats": stats,
+            });
+            report["parse_time_ns"] = Value::from(run.parse_time.as_nanos() as u64);
+            report["parts"] = run
+                .parts
+                .iter()
+                .map(|part| {
+                    json!({
+                        "part": part.part.number(),
+                        "answer": answer_value(&part.answer),
+                        "time_ns": part.time.as_nanos() as u64,
+                        "caches": part
+                            .caches
+                            .iter()
+                            .map(|(name, stats)| {
+                                json!({
+                                    "name": name,
+                                    "hits": stats.hits,
+                                    "misses": stats.misses,
+                                    "entries": stats.entries,
+                                })
+                            })
+                            .collect::<Vec<_>>(),
+                    })
+                })
+                .collect();
+            report["diagnostics"] = json!([]);
+        }
+        Err(e) => {
+            report["diagnostics"] = e.chain().map(|cause| cause.to_string()).collect();
+        }
+    }
+    report
+}
@@ -0,0 +1,458 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use input_parser::InputSource;
+use recorder::{Format, Recorder};
+use solution::{runner, Part, Runner};
+
+mod bench;
+mod json;
+mod verify;
+
+use bench::{BenchResults, Change};
+
+const USAGE: &str = "usage: aoc <day|all> [--part <1|2>] [--input <path|->] [--json]
+       aoc bench <day|all> [--part <1|2>] [--runs <n>] [--threshold <percent>]
+       aoc verify <day|all>
+       aoc gen <day> [--size <n>] [--seed <n>] [--output <path>]
+       aoc record <day> --output <path.cast|path.gif> [--input <path|->] [--every <n>] [--limit <n>] [--delay <ms>]";
+
+/// Results of the previous benchmark, which the next one is compared against
+const BENCH_RESULTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../bench-results.tsv");
+/// No further benchmark runs of a day are started after this time
+const BENCH_BUDGET: Duration = Duration::from_secs(10);
+
+fn solutions() -> Vec<Box<dyn Runner>> {
+    vec![
+        runner::<day01::Day01>(),
+        runner::<day02::Day02>(),
+        runner::<day03::Day03>(),
+        runner::<day04::Day04>(),
+        runner::<day05::Day05>(),
+        runner::<day06::Day06>(),
+        runner::<day07::Day07>(),
+        runner::<day08::Day08>(),
+        runner::<day09::Day09>(),
+        runner::<day10::Day10>(),
+        runner::<day11::Day11>(),
+        runner::<day12::Day12>(),
+        runner::<day13::Day13>(),
+        runner::<day14::Day14>(),
+        runner::<day15::Day15>(),
+        runner::<day16::Day16>(),
+        runner::<day17::Day17>(),
+        runner::<day18::Day18>(),
+        runner::<day19::Day19>(),
+        runner::<day20::Day20>(),
+        runner::<day21::Day21>(),
+        runner::<day22::Day22>(),
+        runner::<day23::Day23>(),
+        runner::<day24::Day24>(),
+        runner::<day25::Day25>(),
+    ]
+}
+
+enum Selection {
+    All,
+    Day(u8),
+}
+
+enum Mode {
+    Run {
+        /// Prints one JSON object per day instead of the answers as text
+        json: bool,
+    },
+    Bench {
+        runs: usize,
+        /// Slowdown of the median in percent above which a phase counts as regressed
+        threshold: f64,
+    },
+    /// Compares the answers against the manifest of each day
+    Verify,
+    /// Writes a random input for a single day, after checking that all its parts solve it
+    Gen {
+        size: Option<usize>,
+        seed: u64,
+        /// Standard output if not given
+        output: Option<PathBuf>,
+    },
+    /// Exports the visualization of a single day as an animation
+    Record {
+        output: PathBuf,
+        every: usize,
+        limit: usize,
+        delay: Duration,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Run,
+    Bench,
+    Verify,
+    Gen,
+    Record,
+}
+
+struct Args {
+    mode: Mode,
+    selection: Selection,
+    /// Part given with `--part`, all parts of each day if `None`
+    part: Option<Part>,
+    /// Overrides the embedded input, only allowed for a single day
+    input: Option<InputSource>,
+}
+
+fn option_value<I: Iterator<Item = String>, T: std::str::FromStr>(
+    args: &mut I,
+    name: &str,
+) -> Result<T> {
+    let value = args
+        .next()
+        .ok_or_else(|| anyhow!("{} needs a value", name))?;
+    value
+        .parse()
+        .map_err(|_| anyhow!("invalid value {:?} for {}", value, name))
+}
+
+fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<Args> {
+    let mut args = args.peekable();
+    let command = match args.peek().map(String::as_str) {
+        Some("bench") => Command::Bench,
+        Some("verify") => Command::Verify,
+        Some("gen") => Command::Gen,
+        Some("record") => Command::Record,
+        _ => Command::Run,
+    };
+    if command != Command::Run {
+        args.next();
+    }
+    let mut selection = None;
+    let mut part = None;
+    let mut input = None;
+    let mut runs = 10;
+    let mut threshold = 10.0;
+    let mut json = false;
+    let mut size = None;
+    let mut seed = 0;
+    let mut output = None;
+    let mut every = 1;
+    let mut limit = 1000;
+    let mut delay = 100;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" | "-n" if command == Command::Bench => {
+                runs = option_value(&mut args, "--runs")?;
+                if runs == 0 {
+                    bail!("--runs must be at least 1");
+                }
+            }
+            "--threshold" | "-t" if command == Command::Bench => {
+                threshold = option_value(&mut args, "--threshold")?
+            }
+            "--size" | "-s" if command == Command::Gen => {
+                size = Some(option_value(&mut args, "--size")?)
+            }
+            "--seed" if command == Command::Gen => seed = option_value(&mut args, "--seed")?,
+            "--every" if command == Command::Record => every = option_value(&mut args, "--every")?,
+            "--limit" if command == Command::Record => limit = option_value(&mut args, "--limit")?,
+            "--delay" if command == Command::Record => delay = option_value(&mut args, "--delay")?,
+            "--output" | "-o" if matches!(command, Command::Gen | Command::Record) => {
+                output = Some(option_value(&mut args, "--output")?)
+            }
+            "--json" if command == Command::Run => json = true,
+            "--part" | "-p" if matches!(command, Command::Run | Command::Bench) => {
+                let value = args.next().ok_or_else(|| anyhow!("--part needs a value"))?;
+                let number = value
+                    .parse()
+                    .ok()
+                    .and_then(Part::from_number)
+                    .ok_or_else(|| anyhow!("invalid part {:?} (expected 1 or 2)", value))?;
+                part = Some(number);
+            }
+            "--input" | "-i" if matches!(command, Command::Run | Command::Record) => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--input needs a value"))?;
+                input = Some(InputSource::from_arg(Some(value), InputSource::Stdin));
+            }
+            "all" if selection.is_none() && !matches!(command, Command::Gen | Command::Record) => {
+                selection = Some(Selection::All)
+            }
+            day if selection.is_none() && !day.starts_with('-') => {
+                let day = day
+                    .parse()
+                    .map_err(|_| anyhow!("invalid day {:?} (expected a number or \"all\")", day))?;
+                selection = Some(Selection::Day(day));
+            }
+            other => bail!("unexpected argument {:?}", other),
+        }
+    }
+    let selection = selection.ok_or_else(|| anyhow!("no day given"))?;
+    if input.is_some() && matches!(selection, Selection::All) {
+        bail!("--input can only be used for a single day");
+    }
+    let mode = match command {
+        Command::Run => Mode::Run { json },
+        Command::Bench => Mode::Bench { runs, threshold },
+        Command::Verify => Mode::Verify,
+        Command::Gen => Mode::Gen { size, seed, output },
+        Command::Record => Mode::Record {
+            output: output.ok_or_else(|| anyhow!("record needs --output"))?,
+            every,
+            limit,
+            delay: Duration::from_millis(delay),
+        },
+    };
+    Ok(Args {
+        mode,
+        selection,
+        part,
+        input,
+    })
+}
+
+/// Parts of `solution` to run: all it has, or the selected one if it has it.
+///
+/// A single day is asked for the selected part even if it lacks it, so that it can report so.
+fn selected_parts(solution: &dyn Runner, part: Option<Part>, selection: &Selection) -> Vec<Part> {
+    match (part, selection) {
+        (None, _) => solution.parts().to_vec(),
+        (Some(part), Selection::All) => solution
+            .parts()
+            .iter()
+            .copied()
+            .filter(|&p| p == part)
+            .collect(),
+        (Some(part), Selection::Day(_)) => vec![part],
+    }
+}
+
+fn run_day(solution: &dyn Runner, input: &str, parts: &[Part], json: bool) -> Result<()> {
+    if json {
+        let run = solution.run(input, parts);
+        println!("{}", json::day_report(solution, &run));
+        return run.map(|_| ());
+    }
+    println!("Day {}: {}", solution.day(), solution.title());
+    let run = solution.run(input, parts)?;
+    for part in run.parts {
+        println!("{}", part);
+        for (name, stats) in &part.caches {
+            println!("  cache {}: {}", name, stats);
+        }
+    }
+    Ok(())
+}
+
+/// Benchmarks `parts[i]` of `solutions[i]` for every day.
+fn run_bench(
+    solutions: &[&dyn Runner],
+    parts: &[Vec<Part>],
+    runs: usize,
+    threshold: f64,
+) -> Result<()> {
+    let path = Path::new(BENCH_RESULTS);
+    let mut results = BenchResults::load(path)?;
+    let previous = results.clone();
+    let mut regressions = 0;
+    println!(
+        "{:>3}  {:<5}  {:>4}  {:>10}  {:>10}  {:>10}  Change",
+        "Day", "Phase", "Runs", "Min", "Median", "Max"
+    );
+    for (solution, parts) in solutions.iter().zip(parts) {
+        let stats = bench::bench(*solution, parts, runs, BENCH_BUDGET)
+            .with_context(|| format!("day {} failed", solution.day()))?;
+        for (phase, stats) in stats {
+            let change = previous
+                .get(solution.day(), phase)
+                .map(|p| Change::between(p, &stats, threshold));
+            if change.map(|c| c.regression).unwrap_or(false) {
+                regressions += 1;
+            }
+            println!(
+                "{:>3}  {:<5}  {:>4}  {:>10}  {:>10}  {:>10}  {}",
+                solution.day(),
+                phase,
+                stats.runs,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max),
+                change
+                    .map(|c| c.to_string())
+                    .unwrap_or_else(|| String::from("-"))
+            );
+            results.insert(solution.day(), phase, stats);
+        }
+    }
+    results.save(path)?;
+    if regressions > 0 {
+        println!(
+            "{} phase(s) got more than {}% slower than in the previous run",
+            regressions, threshold
+        );
+    }
+    Ok(())
+}
+
+fn show_answer(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("\n{}", answer)
+    } else {
+        format!(" {}", answer)
+    }
+}
+
+fn run_verify(solutions: &[&dyn Runner]) -> Result<()> {
+    let mut failed = 0;
+    for solution in solutions {
+        match verify::verify(*solution) {
+            Ok((checked, mismatches)) if mismatches.is_empty() => {
+                println!("Day {}: {} answers ok", solution.day(), checked)
+            }
+            Ok((checked, mismatches)) => {
+                println!(
+                    "Day {}: {} of {} answers wrong",
+                    solution.day(),
+                    mismatches.len(),
+                    checked
+                );
+                for m in mismatches {
+                    println!("  {} of {}", m.part, m.input);
+                    println!("    expected:{}", show_answer(&m.expected));
+                    println!("    actual:{}", show_answer(&m.actual));
+                }
+                failed += 1;
+            }
+            Err(e) => {
+                println!("Day {}: failed: {:#}", solution.day(), e);
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        eprintln!("{} of {} days did not verify", failed, solutions.len());
+        process::exit(1);
+    }
+    Ok(())
+}
+
+fn run_gen(
+    solution: &dyn Runner,
+    size: Option<usize>,
+    seed: u64,
+    output: Option<&Path>,
+) -> Result<()> {
+    let generator = generators::generator(solution.day())
+        .ok_or_else(|| anyhow!("there is no generator for day {}", solution.day()))?;
+    let input = generator.generate(size.unwrap_or(generator.default_size), seed);
+    solution
+        .run(&input, solution.parts())
+        .context("the generated input cannot be solved")?;
+    match output {
+        Some(path) => {
+            fs::write(path, input).with_context(|| format!("could not write {}", path.display()))
+        }
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn run_record(
+    solution: &dyn Runner,
+    input: &str,
+    output: &Path,
+    mut recorder: Recorder,
+    delay: Duration,
+) -> Result<()> {
+    // Fail before the possibly long simulation if the output cannot be written anyway
+    Format::from_path(output)?;
+    solution.visualize(input, &mut recorder)?;
+    recorder
+        .export(output, delay)
+        .with_context(|| format!("could not export to {}", output.display()))?;
+    println!(
+        "Recorded {} frames of day {} to {}",
+        recorder.frames().len(),
+        solution.day(),
+        output.display()
+    );
+    Ok(())
+}
+
+fn read_input(solution: &dyn Runner, source: &Option<InputSource>) -> Result<String> {
+    match source {
+        Some(source) => source
+            .read_to_string()
+            .with_context(|| format!("could not read {}", source)),
+        None => Ok(String::from(solution.input())),
+    }
+}
+
+fn main() -> Result<()> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|a| a == "--help" || a == "-h") {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+    let args = parse_args(args.into_iter()).context(USAGE)?;
+    let solutions = solutions();
+    let selected: Vec<&dyn Runner> = match args.selection {
+        Selection::All => solutions.iter().map(|s| s.as_ref()).collect(),
+        Selection::Day(day) => vec![solutions
+            .iter()
+            .find(|s| s.day() == day)
+            .ok_or_else(|| anyhow!("there is no solution for day {}", day))?
+            .as_ref()],
+    };
+    match args.mode {
+        Mode::Run { json } => match args.selection {
+            Selection::Day(day) => {
+                let input = read_input(selected[0], &args.input)?;
+                let parts = selected_parts(selected[0], args.part, &args.selection);
+                run_day(selected[0], &input, &parts, json)
+                    .with_context(|| format!("day {} failed", day))
+            }
+            Selection::All => {
+                let mut failed = 0;
+                for solution in &selected {
+                    let parts = selected_parts(*solution, args.part, &args.selection);
+                    if let Err(e) = run_day(*solution, solution.input(), &parts, json) {
+                        eprintln!("Error: day {} failed: {:#}", solution.day(), e);
+                        failed += 1;
+                    }
+                }
+                if failed > 0 {
+                    eprintln!("{} of {} days failed", failed, selected.len());
+                    process::exit(1);
+                }
+                Ok(())
+            }
+        },
+        Mode::Bench { runs, threshold } => {
+            let parts: Vec<_> = selected
+                .iter()
+                .map(|s| selected_parts(*s, args.part, &args.selection))
+                .collect();
+            run_bench(&selected, &parts, runs, threshold)
+        }
+        Mode::Verify => run_verify(&selected),
+        Mode::Gen { size, seed, output } => run_gen(selected[0], size, seed, output.as_deref()),
+        Mode::Record {
+            output,
+            every,
+            limit,
+            delay,
+        } => {
+            let input = read_input(selected[0], &args.input)?;
+            let recorder = Recorder::new().every(every).limit(limit);
+            run_record(selected[0], &input, &output, recorder, delay)
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use solution::{Part, Runner};
+
+/// Name under which the manifest refers to the input compiled into a solution
+const EMBEDDED_INPUT: &str = "input";
+
+/// Expected answer of one part for one input, as listed in a day's `answers` manifest.
+#[derive(Clone, Debug)]
+pub struct Expected {
+    /// File name of the input next to the manifest
+    pub input: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+/// An answer that does not match the manifest.
+#[derive(Clone, Debug)]
+pub struct Mismatch {
+    pub input: String,
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Directory of a day's crate, which holds its inputs and the answers manifest.
+pub fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("{:02}", day))
+}
+
+/// Parses a manifest with one `<input> <part> <answer>` entry per line.
+///
+/// Blank lines and lines starting with `#` are ignored, newlines in answers are written as `\n`.
+pub fn parse_manifest(manifest: &str) -> Result<Vec<Expected>> {
+    manifest
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(idx, line)| {
+            let mut fields = line.splitn(3, ' ');
+            let (input, part, answer) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(input), Some(part), Some(answer)) => (input, part, answer),
+                _ => bail!("line {}: expected <input> <part> <answer>", idx + 1),
+            };
+            let part = part
+                .parse()
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(|| anyhow!("line {}: invalid part {:?}", idx + 1, part))?;
+            Ok(Expected {
+                input: String::from(input),
+                part,
+                answer: answer.replace("\\n", "\n"),
+            })
+        })
+        .collect()
+}
+
+/// Runs a solution on every input of its manifest and returns the answers that differ.
+///
+/// The returned count is the number of answers checked.
+pub fn verify(solution: &dyn Runner) -> Result<(usize, Vec<Mismatch>)> {
+    let dir = day_dir(solution.day());
+    let manifest_path = dir.join("answers");
+    let manifest = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("could not read {}", manifest_path.display()))?;
+    let expected = parse_manifest(&manifest)
+        .with_context(|| format!("{} is malformed", manifest_path.display()))?;
+    if expected.is_empty() {
+        bail!("{} lists no answers", manifest_path.display());
+    }
+
+    let mut by_input: BTreeMap<&str, Vec<&Expected>> = BTreeMap::new();
+    for e in &expected {
+        by_input.entry(&e.input).or_default().push(e);
+    }
+    let mut mismatches = Vec::new();
+    for (name, expected) in by_input {
+        let input = if name == EMBEDDED_INPUT {
+            String::from(solution.input())
+        } else {
+            let path = dir.join(name);
+            fs::read_to_string(&path)
+                .with_context(|| format!("could not read {}", path.display()))?
+        };
+        let parts: Vec<Part> = expected.iter().map(|e| e.part).collect();
+        let run = solution
+            .run(&input, &parts)
+            .with_context(|| format!("failed on {}", name))?;
+        for (e, actual) in expected.iter().zip(run.parts) {
+            if e.answer != actual.answer {
+                mismatches.push(Mismatch {
+                    input: String::from(name),
+                    part: e.part,
+                    expected: e.answer.clone(),
+                    actual: actual.answer,
+                });
+            }
+        }
+    }
+    Ok((expected.len(), mismatches))
+}
@@ -0,0 +1,13 @@
+//! Sonar depths as a random walk that tends to go deeper; `size` is the number of measurements.
+
+use rand::Rng;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut depth: i32 = rng.gen_range(100..200);
+    let mut out = String::new();
+    for _ in 0..size {
+        out.push_str(&format!("{}\n", depth));
+        depth = (depth + rng.gen_range(-10..=20)).max(0);
+    }
+    out
+}
@@ -0,0 +1,25 @@
+//! Submarine commands that never take the submarine above the surface; `size` is the number of commands.
+
+use rand::Rng;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut depth = 0;
+    let mut out = String::new();
+    for _ in 0..size {
+        let amount = rng.gen_range(1..10);
+        let command = match rng.gen_range(0..3) {
+            0 => "forward",
+            1 => {
+                depth += amount;
+                "down"
+            }
+            _ if depth >= amount => {
+                depth -= amount;
+                "up"
+            }
+            _ => "forward",
+        };
+        out.push_str(&format!("{} {}\n", command, amount));
+    }
+    out
+}
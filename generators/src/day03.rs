@@ -0,0 +1,36 @@
+//! Diagnostic report of distinct 12-bit numbers; `size` is the number of lines, made odd and at most 4095.
+//!
+//! The numbers are drawn as a binary prefix tree in which every prefix shared by several numbers
+//! continues with both bits. Together with the odd count this rules out the cases the puzzle leaves
+//! undefined: ties for the gamma rate and rating filters that would remove every number.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const WIDTH: u32 = 12;
+
+/// Adds `n` numbers starting with `prefix` and followed by `width` more bits
+fn subtree<R: Rng>(rng: &mut R, prefix: u32, width: u32, n: usize, numbers: &mut Vec<u32>) {
+    if n == 1 {
+        numbers.push(prefix << width | rng.gen_range(0..1 << width));
+        return;
+    }
+    let half = 1 << (width - 1);
+    let (mid, spread) = (n / 2, n / 4);
+    let zeros = rng
+        .gen_range(mid - spread..=mid + spread)
+        .clamp(n.saturating_sub(half).max(1), (n - 1).min(half));
+    subtree(rng, prefix << 1, width - 1, zeros, numbers);
+    subtree(rng, prefix << 1 | 1, width - 1, n - zeros, numbers);
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let size = (size.clamp(1, (1 << WIDTH) - 1) - 1) / 2 * 2 + 1;
+    let mut numbers = Vec::with_capacity(size);
+    subtree(rng, 0, WIDTH, size, &mut numbers);
+    numbers.shuffle(rng);
+    numbers
+        .iter()
+        .map(|n| format!("{:0width$b}\n", n, width = WIDTH as usize))
+        .collect()
+}
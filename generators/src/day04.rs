@@ -0,0 +1,27 @@
+//! Bingo subsystem with all numbers drawn, so every board wins eventually; `size` is the number of boards.
+
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+const MAX_NUMBER: usize = 100;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut drawn: Vec<usize> = (0..MAX_NUMBER).collect();
+    drawn.shuffle(rng);
+    let mut out = drawn
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    out.push('\n');
+    for _ in 0..size.max(1) {
+        out.push('\n');
+        let numbers = index::sample(rng, MAX_NUMBER, 25).into_vec();
+        for row in numbers.chunks(5) {
+            let row: Vec<_> = row.iter().map(|n| format!("{:2}", n)).collect();
+            out.push_str(&row.join(" "));
+            out.push('\n');
+        }
+    }
+    out
+}
@@ -0,0 +1,40 @@
+//! Vent lines that are horizontal, vertical or diagonal at exactly 45 degrees; `size` is the number of lines.
+
+use rand::Rng;
+
+const MAX_COORD: i32 = 1000;
+
+/// Number of steps from `pos` in direction `d` that stay within the diagram
+fn room(pos: i32, d: i32) -> i32 {
+    match d {
+        0 => MAX_COORD,
+        d if d > 0 => MAX_COORD - 1 - pos,
+        _ => pos,
+    }
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let (x1, y1) = (rng.gen_range(0..MAX_COORD), rng.gen_range(0..MAX_COORD));
+        let (dx, dy) = match rng.gen_range(0..3) {
+            0 => (1, 0),
+            1 => (0, 1),
+            _ => (1, if rng.gen_bool(0.5) { 1 } else { -1 }),
+        };
+        let (dx, dy) = if rng.gen_bool(0.5) {
+            (dx, dy)
+        } else {
+            (-dx, -dy)
+        };
+        let length = rng.gen_range(0..=room(x1, dx).min(room(y1, dy)));
+        out.push_str(&format!(
+            "{},{} -> {},{}\n",
+            x1,
+            y1,
+            x1 + dx * length,
+            y1 + dy * length
+        ));
+    }
+    out
+}
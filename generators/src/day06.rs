@@ -0,0 +1,10 @@
+//! Lanternfish timers between 1 and 5 like in the puzzle; `size` is the number of fish.
+
+use rand::Rng;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let timers: Vec<_> = (0..size)
+        .map(|_| rng.gen_range(1..=5).to_string())
+        .collect();
+    format!("{}\n", timers.join(","))
+}
@@ -0,0 +1,14 @@
+//! Crab positions below 2000 that cluster at small values like in the puzzle; `size` is the
+//! number of crabs (at least one).
+
+use rand::Rng;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let positions: Vec<_> = (0..size.max(1))
+        .map(|_| {
+            let r: f64 = rng.gen();
+            ((r * r * 2000.0) as u32).to_string()
+        })
+        .collect();
+    format!("{}\n", positions.join(","))
+}
@@ -0,0 +1,33 @@
+//! Notes on displays with randomly rewired segments; `size` is the number of displays.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Segments of `digit` after rewiring, in random order
+fn pattern<R: Rng>(rng: &mut R, wiring: &[char], digit: &str) -> String {
+    let mut segments: Vec<char> = digit.bytes().map(|s| wiring[(s - b'a') as usize]).collect();
+    segments.shuffle(rng);
+    segments.into_iter().collect()
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut wiring: Vec<char> = "abcdefg".chars().collect();
+        wiring.shuffle(rng);
+        let mut observed: Vec<String> = DIGITS.iter().map(|d| pattern(rng, &wiring, d)).collect();
+        observed.shuffle(rng);
+        let output: Vec<String> = (0..4)
+            .map(|_| {
+                let digit = DIGITS.choose(rng).unwrap();
+                pattern(rng, &wiring, digit)
+            })
+            .collect();
+        out.push_str(&format!("{} | {}\n", observed.join(" "), output.join(" ")));
+    }
+    out
+}
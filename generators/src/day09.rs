@@ -0,0 +1,63 @@
+//! Height map of basins around random low points, separated by ridges of height 9; `size` is
+//! the number of rows and columns (at least 6).
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Centers closer than this may lie on the ridge around a neighboring basin
+const MIN_DISTANCE: usize = 3;
+/// Smallest side length with room for a third center wherever the first two lie
+const MIN_SIZE: usize = 6;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let size = size.max(MIN_SIZE);
+    let mut centers: Vec<(usize, usize)> = Vec::new();
+    for _ in 0..size * size / 50 + 3 {
+        let far = |centers: &[(usize, usize)], (i, j): (usize, usize)| {
+            centers
+                .iter()
+                .all(|&(ci, cj)| i.abs_diff(ci) + j.abs_diff(cj) >= MIN_DISTANCE)
+        };
+        let mut center = (0..100)
+            .map(|_| (rng.gen_range(0..size), rng.gen_range(0..size)))
+            .find(|&c| far(&centers, c));
+        if center.is_none() {
+            let free: Vec<_> = (0..size)
+                .flat_map(|i| (0..size).map(move |j| (i, j)))
+                .filter(|&c| far(&centers, c))
+                .collect();
+            center = free.choose(rng).copied();
+        }
+        match center {
+            Some(center) => centers.push(center),
+            None => break,
+        }
+    }
+    // Every location belongs to its nearest center and rises with the distance to it
+    let nearest = |i: usize, j: usize| {
+        centers
+            .iter()
+            .enumerate()
+            .map(|(c, &(ci, cj))| (i.abs_diff(ci) + j.abs_diff(cj), c))
+            .min()
+            .unwrap()
+    };
+    let regions: Vec<Vec<_>> = (0..size)
+        .map(|i| (0..size).map(|j| nearest(i, j)).collect())
+        .collect();
+    let mut map = String::with_capacity(size * (size + 1));
+    for i in 0..size {
+        for j in 0..size {
+            let (distance, region) = regions[i][j];
+            // Locations next to a region with a lower index form the ridge between the two
+            let on_ridge = (i > 0 && regions[i - 1][j].1 < region)
+                || (j > 0 && regions[i][j - 1].1 < region)
+                || (i + 1 < size && regions[i + 1][j].1 < region)
+                || (j + 1 < size && regions[i][j + 1].1 < region);
+            let height = if on_ridge { 9 } else { distance.min(8) };
+            map.push(char::from(b'0' + height as u8));
+        }
+        map.push('\n');
+    }
+    map
+}
@@ -0,0 +1,69 @@
+//! Navigation subsystem lines that are either corrupted or incomplete; `size` is the number of lines.
+//!
+//! The number of incomplete lines is odd, so the middle completion score is well defined.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const BRACKETS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+/// Keeps completion scores of incomplete lines small enough for the solution
+const MAX_DEPTH: usize = 20;
+
+fn line<R: Rng>(rng: &mut R, corrupted: bool) -> String {
+    let length = rng.gen_range(20..110);
+    let corrupt_at = rng.gen_range(1..length);
+    let mut stack: Vec<char> = Vec::new();
+    let mut line = String::new();
+    for i in 0..length {
+        if corrupted && i >= corrupt_at && !stack.is_empty() {
+            let expected = stack.pop().unwrap();
+            let wrong: Vec<_> = BRACKETS
+                .iter()
+                .map(|&(_, close)| close)
+                .filter(|&c| c != expected)
+                .collect();
+            line.push(*wrong.choose(rng).unwrap());
+            // Anything may follow the first illegal character
+            line.extend((i + 1..length).map(|_| {
+                let (open, close) = BRACKETS.choose(rng).unwrap();
+                if rng.gen_bool(0.5) {
+                    *open
+                } else {
+                    *close
+                }
+            }));
+            return line;
+        }
+        if stack.is_empty() || (stack.len() < MAX_DEPTH && rng.gen_bool(0.55)) {
+            let (open, close) = BRACKETS.choose(rng).unwrap();
+            line.push(*open);
+            stack.push(*close);
+        } else {
+            line.push(stack.pop().unwrap());
+        }
+    }
+    if corrupted {
+        // The stack was never non-empty at the chosen position, close it with a wrong bracket
+        let (open, close) = BRACKETS.choose(rng).unwrap();
+        let wrong = BRACKETS.iter().find(|&&(_, c)| c != *close).unwrap().1;
+        line.push(*open);
+        line.push(wrong);
+    } else if stack.is_empty() {
+        line.push(BRACKETS.choose(rng).unwrap().0);
+    }
+    line
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut corrupted: Vec<bool> = (0..size.max(1)).map(|_| rng.gen_bool(0.5)).collect();
+    let incomplete = corrupted.iter().filter(|&&c| !c).count();
+    if incomplete % 2 == 0 {
+        if let Some(last) = corrupted.last_mut() {
+            *last = !*last;
+        }
+    }
+    corrupted
+        .into_iter()
+        .map(|c| format!("{}\n", line(rng, c)))
+        .collect()
+}
@@ -0,0 +1,69 @@
+//! Square grid of random energy levels that synchronizes within a bounded number of steps;
+//! `size` is the number of rows and columns.
+//!
+//! Not every grid synchronizes, so candidates are simulated and discarded until one does.
+
+use rand::Rng;
+
+/// Steps after which a candidate grid is discarded
+const MAX_STEPS: usize = 2000;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    loop {
+        let grid: Vec<Vec<u8>> = (0..size)
+            .map(|_| (0..size).map(|_| rng.gen_range(0..=9)).collect())
+            .collect();
+        if synchronizes(grid.clone()) {
+            return grid
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&e| char::from(b'0' + e))
+                        .collect::<String>()
+                        + "\n"
+                })
+                .collect();
+        }
+    }
+}
+
+fn synchronizes(mut grid: Vec<Vec<u8>>) -> bool {
+    let size = grid.len();
+    for _ in 0..MAX_STEPS {
+        let mut queue = Vec::new();
+        for (i, row) in grid.iter_mut().enumerate() {
+            for (j, energy) in row.iter_mut().enumerate() {
+                *energy += 1;
+                if *energy == 10 {
+                    queue.push((i, j));
+                }
+            }
+        }
+        let mut flashes = 0;
+        while let Some((i, j)) = queue.pop() {
+            flashes += 1;
+            let rows = i.saturating_sub(1)..(i + 2).min(size);
+            for (ni, row) in grid.iter_mut().enumerate().take(rows.end).skip(rows.start) {
+                let cols = j.saturating_sub(1)..(j + 2).min(size);
+                for (nj, energy) in row.iter_mut().enumerate().take(cols.end).skip(cols.start) {
+                    if (ni, nj) != (i, j) {
+                        *energy += 1;
+                        if *energy == 10 {
+                            queue.push((ni, nj));
+                        }
+                    }
+                }
+            }
+        }
+        for energy in grid.iter_mut().flatten() {
+            if *energy > 9 {
+                *energy = 0;
+            }
+        }
+        if flashes == size * size {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,64 @@
+//! Cave systems in which no two big caves are connected, so the number of paths is finite;
+//! `size` is the number of caves besides `start` and `end`.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+fn cave_name<R: Rng>(rng: &mut R, big: bool, taken: &[String]) -> String {
+    // Names grow with the system, so at least half of the names of each length remain free
+    let mut length = 2;
+    while 26usize.pow(length) < 2 * taken.len() {
+        length += 1;
+    }
+    loop {
+        let name: String = (0..length)
+            .map(|_| {
+                let c = rng.gen_range(b'a'..=b'z') as char;
+                if big {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect();
+        if !taken.contains(&name) && name != "start" && name != "end" {
+            return name;
+        }
+    }
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut caves = vec![String::from("start"), String::from("end")];
+    for i in 0..size {
+        // Every third cave is big, which is roughly the ratio of the puzzle input
+        let name = cave_name(rng, i % 3 == 0, &caves);
+        caves.push(name);
+    }
+    let big = |name: &str| name.chars().all(|c| c.is_ascii_uppercase());
+
+    let mut candidates: Vec<(usize, usize)> = (0..caves.len())
+        .flat_map(|i| (i + 1..caves.len()).map(move |j| (i, j)))
+        .filter(|&(i, j)| !(big(&caves[i]) && big(&caves[j])))
+        .collect();
+    candidates.shuffle(rng);
+    // Make sure start and end are connected to the rest of the system
+    for endpoint in 0..2 {
+        if let Some(pos) = candidates.iter().position(|&(i, _)| i == endpoint) {
+            let edge = candidates.remove(pos);
+            candidates.insert(0, edge);
+        }
+    }
+    let edges = (caves.len() * 7 / 4).min(candidates.len());
+
+    candidates
+        .into_iter()
+        .take(edges)
+        .map(|(i, j)| {
+            if rng.gen_bool(0.5) {
+                format!("{}-{}\n", caves[i], caves[j])
+            } else {
+                format!("{}-{}\n", caves[j], caves[i])
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,70 @@
+//! Transparent paper whose dots fold into a random 40x6 picture; `size` is the number of dots.
+//!
+//! The dots are picked from the picture and unfolded at random, so none ever lies on a fold line.
+
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const FOLDED_WIDTH: i32 = 40;
+const FOLDED_HEIGHT: i32 = 6;
+const FOLDS_PER_AXIS: usize = 5;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let mut axes: Vec<char> = ['x'; FOLDS_PER_AXIS]
+        .iter()
+        .chain(['y'; FOLDS_PER_AXIS].iter())
+        .copied()
+        .collect();
+    axes.shuffle(rng);
+    // Unfold backwards from the final size, a fold along n halves a side of length 2n + 1
+    let (mut width, mut height) = (FOLDED_WIDTH, FOLDED_HEIGHT);
+    let mut folds = Vec::new();
+    for &axis in axes.iter().rev() {
+        if axis == 'x' {
+            folds.push((axis, width));
+            width = 2 * width + 1;
+        } else {
+            folds.push((axis, height));
+            height = 2 * height + 1;
+        }
+    }
+    folds.reverse();
+
+    let mut picture: Vec<(i32, i32)> = (0..FOLDED_WIDTH)
+        .flat_map(|x| (0..FOLDED_HEIGHT).map(move |y| (x, y)))
+        .filter(|_| rng.gen_bool(0.4))
+        .collect();
+    if picture.is_empty() {
+        picture.push((0, 0));
+    }
+
+    let mut dots = HashSet::new();
+    let mut attempts = 0;
+    while dots.len() < size && attempts < 100 * size {
+        attempts += 1;
+        let (mut x, mut y) = *picture.choose(rng).unwrap();
+        for &(axis, line) in folds.iter().rev() {
+            if rng.gen_bool(0.5) {
+                if axis == 'x' {
+                    x = 2 * line - x;
+                } else {
+                    y = 2 * line - y;
+                }
+            }
+        }
+        dots.insert((x, y));
+    }
+    let mut dots: Vec<_> = dots.into_iter().collect();
+    dots.sort_unstable();
+    dots.shuffle(rng);
+
+    let mut out: String = dots.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect();
+    out.push('\n');
+    for (axis, line) in folds {
+        out.push_str(&format!("fold along {}={}\n", axis, line));
+    }
+    out
+}
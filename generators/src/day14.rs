@@ -0,0 +1,21 @@
+//! Polymer template with an insertion rule for every pair of elements; `size` is the template length.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const ELEMENTS: [char; 10] = ['B', 'C', 'F', 'H', 'K', 'N', 'O', 'P', 'S', 'V'];
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let template: String = (0..size.max(2))
+        .map(|_| *ELEMENTS.choose(rng).unwrap())
+        .collect();
+    let mut rules = Vec::new();
+    for &first in &ELEMENTS {
+        for &second in &ELEMENTS {
+            let insert = *ELEMENTS.choose(rng).unwrap();
+            rules.push(format!("{}{} -> {}\n", first, second, insert));
+        }
+    }
+    rules.shuffle(rng);
+    format!("{}\n\n{}", template, rules.concat())
+}
@@ -0,0 +1,16 @@
+//! Square cave of risk levels between 1 and 9; `size` is the side length.
+
+use rand::Rng;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| char::from(b'0' + rng.gen_range(1..=9)))
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
@@ -0,0 +1,113 @@
+//! BITS transmission of a random packet tree; `size` is the approximate number of packets.
+//!
+//! Product packets only multiply literals, which keeps the value of every expression within a `u128`.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const MAX_DEPTH: usize = 8;
+const OPERATORS: [u8; 7] = [0, 1, 2, 3, 5, 6, 7];
+
+enum Packet {
+    Literal {
+        version: u8,
+        value: u64,
+    },
+    Operator {
+        version: u8,
+        type_id: u8,
+        subpackets: Vec<Packet>,
+    },
+}
+
+fn packet<R: Rng>(rng: &mut R, budget: &mut usize, depth: usize, literal: bool) -> Packet {
+    *budget = budget.saturating_sub(1);
+    let version = rng.gen_range(0..8);
+    if literal || *budget < 2 || depth >= MAX_DEPTH {
+        return Packet::Literal {
+            version,
+            value: rng.gen_range(0..1 << 16),
+        };
+    }
+    let type_id = *OPERATORS.choose(rng).unwrap();
+    let count = match type_id {
+        5..=7 => 2,
+        1 => rng.gen_range(1..=3),
+        _ => rng.gen_range(1..=5),
+    };
+    let subpackets = (0..count)
+        .map(|_| packet(rng, budget, depth + 1, type_id == 1))
+        .collect();
+    Packet::Operator {
+        version,
+        type_id,
+        subpackets,
+    }
+}
+
+fn push_bits(bits: &mut Vec<bool>, value: u64, width: usize) {
+    bits.extend((0..width).rev().map(|i| value >> i & 1 == 1));
+}
+
+fn encode<R: Rng>(rng: &mut R, packet: &Packet, bits: &mut Vec<bool>) {
+    match packet {
+        Packet::Literal { version, value } => {
+            push_bits(bits, *version as u64, 3);
+            push_bits(bits, 4, 3);
+            let groups = (64 - value.leading_zeros() as usize).div_ceil(4);
+            for group in (0..groups.max(1)).rev() {
+                bits.push(group > 0);
+                push_bits(bits, value >> (4 * group) & 0xf, 4);
+            }
+        }
+        Packet::Operator {
+            version,
+            type_id,
+            subpackets,
+        } => {
+            push_bits(bits, *version as u64, 3);
+            push_bits(bits, *type_id as u64, 3);
+            let mut content = Vec::new();
+            for p in subpackets {
+                encode(rng, p, &mut content);
+            }
+            if content.len() < 1 << 15 && rng.gen_bool(0.5) {
+                bits.push(false);
+                push_bits(bits, content.len() as u64, 15);
+            } else {
+                bits.push(true);
+                push_bits(bits, subpackets.len() as u64, 11);
+            }
+            bits.extend(content);
+        }
+    }
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    // The outermost sum keeps adding packets until the budget is used up
+    let mut budget = size.max(1) - 1;
+    let mut subpackets = Vec::new();
+    while budget > 0 || subpackets.is_empty() {
+        subpackets.push(packet(rng, &mut budget, 1, false));
+    }
+    let root = Packet::Operator {
+        version: rng.gen_range(0..8),
+        type_id: 0,
+        subpackets,
+    };
+    let mut bits = Vec::new();
+    encode(rng, &root, &mut bits);
+    // The transmission consists of whole bytes, padded with zeros
+    bits.resize(bits.len().div_ceil(8) * 8, false);
+    let mut out: String = bits
+        .chunks(4)
+        .map(|nibble| {
+            let value = nibble.iter().fold(0, |acc, &b| acc << 1 | b as u32);
+            std::char::from_digit(value, 16)
+                .unwrap()
+                .to_ascii_uppercase()
+        })
+        .collect();
+    out.push('\n');
+    out
+}
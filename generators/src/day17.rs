@@ -0,0 +1,20 @@
+//! Target area to the right of and below the probe; `size` is the horizontal distance to the target.
+//!
+//! Some horizontal velocity always comes to a halt within the target, like in the puzzle input.
+
+use rand::Rng;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let size = size.max(10) as i64;
+    // Largest horizontal velocity whose probe stops at or before `size`
+    let v = ((((8 * size + 1) as f64).sqrt() - 1.0) / 2.0) as i64;
+    let stop = v * (v + 1) / 2;
+    let x_min = (stop - rng.gen_range(0..=v / 2)).max(1);
+    let x_max = stop + rng.gen_range(1..=v);
+    let y_min = -(size / 2 + rng.gen_range(0..=size / 4));
+    let y_max = (y_min + rng.gen_range(5..=size / 4 + 5)).min(-1);
+    format!(
+        "target area: x={}..{}, y={}..{}\n",
+        x_min, x_max, y_min, y_max
+    )
+}
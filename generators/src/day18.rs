@@ -0,0 +1,25 @@
+//! Reduced snailfish numbers, i.e. nested at most four levels deep with digits as regular numbers;
+//! `size` is the number of snailfish numbers.
+
+use rand::Rng;
+
+const MAX_DEPTH: usize = 4;
+
+fn element<R: Rng>(rng: &mut R, depth: usize) -> String {
+    if depth < MAX_DEPTH && rng.gen_bool(0.6) {
+        pair(rng, depth + 1)
+    } else {
+        rng.gen_range(0..10).to_string()
+    }
+}
+
+fn pair<R: Rng>(rng: &mut R, depth: usize) -> String {
+    format!("[{},{}]", element(rng, depth), element(rng, depth))
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    // Part 2 adds two different numbers
+    (0..size.max(2))
+        .map(|_| format!("{}\n", pair(rng, 1)))
+        .collect()
+}
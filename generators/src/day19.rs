@@ -0,0 +1,79 @@
+//! Scanners in random orientations, each of which shares at least 12 beacons with an earlier
+//! one; `size` is the number of scanners (at least one).
+
+use geometry::{Point3, Rotation};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Largest coordinate difference at which a scanner detects a beacon
+const RANGE: i64 = 1000;
+/// Largest coordinate difference between a scanner and the scanner it overlaps with
+const MAX_OFFSET: i64 = 1200;
+
+fn random_point<R: Rng>(rng: &mut R, min: Point3, max: Point3) -> Point3 {
+    Point3::new(
+        rng.gen_range(min.x..=max.x),
+        rng.gen_range(min.y..=max.y),
+        rng.gen_range(min.z..=max.z),
+    )
+}
+
+fn detects(scanner: Point3, beacon: Point3) -> bool {
+    let d = beacon - scanner;
+    d.x.abs() <= RANGE && d.y.abs() <= RANGE && d.z.abs() <= RANGE
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let range = Point3::new(RANGE, RANGE, RANGE);
+    let offset = Point3::new(MAX_OFFSET, MAX_OFFSET, MAX_OFFSET);
+    let mut scanners = vec![Point3::ORIGIN];
+    let mut beacons = Vec::new();
+    for _ in 1..size.max(1) {
+        let parent = *scanners.choose(rng).unwrap();
+        let scanner = random_point(rng, parent - offset, parent + offset);
+        // The overlap of both detection cubes holds the shared beacons
+        let low = Point3::new(
+            parent.x.max(scanner.x),
+            parent.y.max(scanner.y),
+            parent.z.max(scanner.z),
+        ) - range;
+        let high = Point3::new(
+            parent.x.min(scanner.x),
+            parent.y.min(scanner.y),
+            parent.z.min(scanner.z),
+        ) + range;
+        for _ in 0..rng.gen_range(12..=15) {
+            beacons.push(random_point(rng, low, high));
+        }
+        scanners.push(scanner);
+    }
+    // Beacons only seen by a single scanner
+    for &scanner in &scanners {
+        for _ in 0..rng.gen_range(5..=12) {
+            beacons.push(random_point(rng, scanner - range, scanner + range));
+        }
+    }
+    beacons.sort_unstable();
+    beacons.dedup();
+
+    let rotations = Rotation::all();
+    let mut input = String::new();
+    for (id, &scanner) in scanners.iter().enumerate() {
+        if id > 0 {
+            input.push('\n');
+        }
+        input += &format!("--- scanner {} ---\n", id);
+        // Scanners report beacons in their own coordinates, which are rotated arbitrarily
+        let to_scanner = rotations.choose(rng).unwrap().inverse();
+        let mut seen: Vec<_> = beacons
+            .iter()
+            .filter(|&&b| detects(scanner, b))
+            .map(|&b| to_scanner.apply(b - scanner))
+            .collect();
+        seen.shuffle(rng);
+        for beacon in seen {
+            input += &format!("{}\n", beacon);
+        }
+    }
+    input
+}
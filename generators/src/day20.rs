@@ -0,0 +1,28 @@
+//! Random algorithm and square image; `size` is the number of rows and columns.
+//!
+//! Like in the puzzle, an algorithm that lights the unlit background turns it off again in the
+//! next step, so the image has finitely many lit pixels after an even number of steps.
+
+use rand::Rng;
+
+fn pixel(lit: bool) -> char {
+    if lit {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut algorithm: Vec<bool> = (0..512).map(|_| rng.gen()).collect();
+    if algorithm[0] {
+        algorithm[511] = false;
+    }
+    let mut input: String = algorithm.into_iter().map(pixel).collect();
+    input.push_str("\n\n");
+    for _ in 0..size.max(1) {
+        input.extend((0..size.max(1)).map(|_| pixel(rng.gen())));
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,15 @@
+//! Starting positions of both players; `size` is not used.
+
+use rand::Rng;
+
+pub fn generate<R: Rng>(rng: &mut R, _size: usize) -> String {
+    (1..=2)
+        .map(|player| {
+            format!(
+                "Player {} starting position: {}\n",
+                player,
+                rng.gen_range(1..=10)
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,39 @@
+//! Reboot steps, the first twenty within the initialization region and the rest far outside of it;
+//! `size` is the number of steps.
+
+use std::ops::RangeInclusive;
+
+use rand::Rng;
+
+const INITIALIZATION_STEPS: usize = 20;
+
+fn range<R: Rng>(rng: &mut R, bounds: RangeInclusive<i32>, extent: RangeInclusive<i32>) -> String {
+    let length = rng.gen_range(extent);
+    let min = rng.gen_range(*bounds.start()..=*bounds.end() - length);
+    format!("{}..{}", min, min + length)
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    (0..size)
+        .map(|i| {
+            let (bounds, extent) = if i < INITIALIZATION_STEPS {
+                (-50..=50, 5..=50)
+            } else {
+                (-100_000..=100_000, 1_000..=30_000)
+            };
+            // The reactor starts with all cubes off, so switching off only makes sense later on
+            let command = if i == 0 || rng.gen_bool(0.7) {
+                "on"
+            } else {
+                "off"
+            };
+            format!(
+                "{} x={},y={},z={}\n",
+                command,
+                range(rng, bounds.clone(), extent.clone()),
+                range(rng, bounds.clone(), extent.clone()),
+                range(rng, bounds, extent)
+            )
+        })
+        .collect()
+}
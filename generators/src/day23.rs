@@ -0,0 +1,40 @@
+//! Burrow with the amphipods shuffled into the rooms; `size` is not used.
+//!
+//! Some shuffles cannot be sorted once the rooms are unfolded, so they are solved and redrawn
+//! until both parts have an answer.
+
+use day23::Day23;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use solution::Solution;
+
+const SORTED: [char; 8] = ['A', 'B', 'C', 'D', 'A', 'B', 'C', 'D'];
+
+fn burrow(amphipods: &[char; 8]) -> String {
+    let row = |r: &[char]| format!("#{}#{}#{}#{}#", r[0], r[1], r[2], r[3]);
+    format!(
+        "#############\n#...........#\n##{}##\n  {}\n  #########\n",
+        row(&amphipods[..4]),
+        row(&amphipods[4..])
+    )
+}
+
+fn solvable(input: &str) -> bool {
+    Day23::parse(input)
+        .and_then(|burrow| Day23::part1(&burrow).and(Day23::part2(&burrow)))
+        .is_ok()
+}
+
+pub fn generate<R: Rng>(rng: &mut R, _size: usize) -> String {
+    let mut amphipods = SORTED;
+    loop {
+        amphipods.shuffle(rng);
+        // An already sorted burrow would make a dull puzzle
+        if amphipods != SORTED {
+            let input = burrow(&amphipods);
+            if solvable(&input) {
+                return input;
+            }
+        }
+    }
+}
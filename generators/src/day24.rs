@@ -0,0 +1,42 @@
+//! MONAD program built from the same per-digit blocks as the puzzle input; `size` is the number of
+//! digits, rounded down to an even number.
+//!
+//! Half of the blocks push a digit onto a base-26 stack kept in z and the other half pop one again.
+//! Each pop accepts its digit only if it differs from the pushed one by a fixed offset of at most 8,
+//! so valid model numbers always exist. The stack never grows deeper than four, which keeps z within
+//! the bounds the solution searches.
+
+use rand::Rng;
+
+const MAX_DEPTH: usize = 4;
+
+fn block(divisor: i64, check: i64, offset: i64) -> String {
+    format!(
+        "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\nadd y 25\n\
+         mul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y\n",
+        divisor, check, offset
+    )
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let digits = (size / 2 * 2).max(2);
+    // Offsets added to the digits that are still on the stack
+    let mut stack: Vec<i64> = Vec::new();
+    let mut out = String::new();
+    for i in 0..digits {
+        let remaining = digits - i;
+        let can_push = stack.len() < MAX_DEPTH && stack.len() < remaining - 1;
+        let push = stack.is_empty() || (can_push && rng.gen_bool(0.5));
+        if push {
+            let offset = rng.gen_range(1..=16);
+            stack.push(offset);
+            // The check is larger than any digit, so pushing blocks never accept their digit
+            out.push_str(&block(1, rng.gen_range(10..=16), offset));
+        } else {
+            let pushed = stack.pop().unwrap();
+            let difference = rng.gen_range(-8..=8);
+            out.push_str(&block(26, difference - pushed, rng.gen_range(1..=16)));
+        }
+    }
+    out
+}
@@ -0,0 +1,38 @@
+//! Sea floor with herds of sea cucumbers facing east and south; `size` is the side length of the map.
+//!
+//! Random maps may keep moving forever, so they are redrawn until the solution sees one stop.
+
+use day25::Day25;
+use rand::Rng;
+use solution::Solution;
+
+fn random_map<R: Rng>(rng: &mut R, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| match rng.gen_range(0..100) {
+                    0..=26 => '>',
+                    27..=53 => 'v',
+                    _ => '.',
+                })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+fn settles(input: &str) -> bool {
+    Day25::parse(input)
+        .and_then(|map| Day25::part1(&map))
+        .is_ok()
+}
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    loop {
+        let input = random_map(rng, size);
+        if settles(&input) {
+            return input;
+        }
+    }
+}
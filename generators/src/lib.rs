@@ -0,0 +1,98 @@
+//! Random puzzle inputs in exactly the format the solutions parse.
+//!
+//! Every generator is deterministic for a given size and seed, so a failing input can be
+//! reproduced from the two numbers alone.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+/// Random number generator passed to the generators, a fixed algorithm keeps seeds reproducible
+pub type GenRng = ChaCha8Rng;
+
+/// Input generator of one day.
+#[derive(Clone, Copy)]
+pub struct Generator {
+    pub day: u8,
+    /// What `size` controls, e.g. the number of lines
+    pub size_meaning: &'static str,
+    /// Size of the checked-in input, or a size the solution handles in reasonable time
+    pub default_size: usize,
+    generate: fn(&mut GenRng, usize) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        let mut rng = GenRng::seed_from_u64(seed);
+        (self.generate)(&mut rng, size)
+    }
+}
+
+pub fn generators() -> Vec<Generator> {
+    macro_rules! generator {
+        ($day:expr, $module:ident, $size_meaning:expr, $default_size:expr) => {
+            Generator {
+                day: $day,
+                size_meaning: $size_meaning,
+                default_size: $default_size,
+                generate: $module::generate::<GenRng>,
+            }
+        };
+    }
+    vec![
+        generator!(1, day01, "depth measurements", 2000),
+        generator!(2, day02, "commands", 1000),
+        generator!(3, day03, "distinct report numbers (at most 4096)", 1000),
+        generator!(4, day04, "boards", 100),
+        generator!(5, day05, "vent lines", 500),
+        generator!(6, day06, "fish", 300),
+        generator!(7, day07, "crabs", 1000),
+        generator!(8, day08, "displays", 200),
+        generator!(9, day09, "rows and columns of the height map", 100),
+        generator!(10, day10, "lines", 100),
+        generator!(11, day11, "rows and columns of the grid", 10),
+        generator!(12, day12, "caves besides start and end", 10),
+        generator!(13, day13, "dots", 800),
+        generator!(14, day14, "template length", 20),
+        generator!(15, day15, "side length of the cave", 100),
+        generator!(16, day16, "packets", 200),
+        generator!(17, day17, "distance to the target", 200),
+        generator!(18, day18, "snailfish numbers", 100),
+        generator!(19, day19, "scanners", 28),
+        generator!(20, day20, "rows and columns of the image", 100),
+        generator!(21, day21, "unused", 0),
+        generator!(22, day22, "reboot steps", 420),
+        generator!(23, day23, "unused", 0),
+        generator!(24, day24, "digits of the model number (even)", 14),
+        generator!(25, day25, "side length of the map", 140),
+    ]
+}
+
+pub fn generator(day: u8) -> Option<Generator> {
+    generators().into_iter().find(|g| g.day == day)
+}
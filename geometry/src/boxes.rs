@@ -0,0 +1,99 @@
+use std::cmp::{max, min};
+
+use crate::Point3;
+
+/// An axis-aligned box of grid cells, including `min` but excluding `max` on every axis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Box3 {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Box3 {
+    pub fn new(min: Point3, max: Point3) -> Self {
+        Box3 { min, max }
+    }
+
+    /// The box of all cells from `first` to `last`, both included.
+    pub fn from_inclusive(first: Point3, last: Point3) -> Self {
+        Box3 {
+            min: first,
+            max: last + Point3::new(1, 1, 1),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x >= self.max.x || self.min.y >= self.max.y || self.min.z >= self.max.z
+    }
+
+    pub fn volume(&self) -> u128 {
+        if self.is_empty() {
+            0
+        } else {
+            let d = self.max - self.min;
+            d.x as u128 * d.y as u128 * d.z as u128
+        }
+    }
+
+    pub fn contains(&self, p: Point3) -> bool {
+        (self.min.x..self.max.x).contains(&p.x)
+            && (self.min.y..self.max.y).contains(&p.y)
+            && (self.min.z..self.max.z).contains(&p.z)
+    }
+
+    /// Whether every cell of `other` is in this box, which is always the case for an empty `other`.
+    pub fn encloses(&self, other: &Box3) -> bool {
+        other.is_empty()
+            || (self.min.x <= other.min.x
+                && self.min.y <= other.min.y
+                && self.min.z <= other.min.z
+                && self.max.x >= other.max.x
+                && self.max.y >= other.max.y
+                && self.max.z >= other.max.z)
+    }
+
+    /// The cells in both boxes, possibly an empty box.
+    pub fn intersect(&self, other: &Box3) -> Box3 {
+        Box3 {
+            min: Point3::new(
+                max(self.min.x, other.min.x),
+                max(self.min.y, other.min.y),
+                max(self.min.z, other.min.z),
+            ),
+            max: Point3::new(
+                min(self.max.x, other.max.x),
+                min(self.max.y, other.max.y),
+                min(self.max.z, other.max.z),
+            ),
+        }
+    }
+
+    /// Splits the cells of this box that are not in `other` into at most six disjoint boxes.
+    pub fn difference(&self, other: &Box3) -> Vec<Box3> {
+        let cut = self.intersect(other);
+        if cut.is_empty() {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        }
+        let mut rest = *self;
+        let mut pieces = Vec::new();
+        // Slice off the parts below and above the cut along each axis in turn
+        for axis in 0..3 {
+            let mut below = rest;
+            let mut above = rest;
+            let (mut below_max, mut above_min) = (below.max.coordinates(), above.min.coordinates());
+            below_max[axis] = cut.min.coordinates()[axis];
+            above_min[axis] = cut.max.coordinates()[axis];
+            below.max = Point3::from_coordinates(below_max);
+            above.min = Point3::from_coordinates(above_min);
+            let (mut rest_min, mut rest_max) = (rest.min.coordinates(), rest.max.coordinates());
+            rest_min[axis] = cut.min.coordinates()[axis];
+            rest_max[axis] = cut.max.coordinates()[axis];
+            rest = Box3::new(
+                Point3::from_coordinates(rest_min),
+                Point3::from_coordinates(rest_max),
+            );
+            pieces.extend([below, above].iter().filter(|b| !b.is_empty()));
+        }
+        pieces
+    }
+}
@@ -0,0 +1,11 @@
+//! Integer geometry in two and three dimensions.
+
+mod boxes;
+mod line;
+mod point;
+mod rotation;
+
+pub use boxes::Box3;
+pub use line::Line2;
+pub use point::{Point2, Point3};
+pub use rotation::Rotation;
@@ -0,0 +1,61 @@
+use crate::Point2;
+
+/// A line segment between two points, both of which belong to the segment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Line2 {
+    pub start: Point2,
+    pub end: Point2,
+}
+
+impl Line2 {
+    pub fn new(start: Point2, end: Point2) -> Self {
+        Line2 { start, end }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    pub fn is_axis_aligned(&self) -> bool {
+        self.is_horizontal() || self.is_vertical()
+    }
+
+    /// Whether the line runs at exactly 45 degrees to the axes.
+    pub fn is_diagonal(&self) -> bool {
+        let d = self.end - self.start;
+        d.x.abs() == d.y.abs() && d.x != 0
+    }
+
+    /// The grid points the line passes through, from start to end (Bresenham's algorithm).
+    ///
+    /// Horizontal, vertical and diagonal lines hit exactly the points on the line.
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let d = self.end - self.start;
+        let step = d.signum();
+        let (dx, dy) = (d.x.abs(), -d.y.abs());
+        let mut current = self.start;
+        let mut error = dx + dy;
+        let mut remaining = dx.max(-dy) + 1;
+        std::iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+            remaining -= 1;
+            let point = current;
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                current.x += step.x;
+            }
+            if doubled <= dx {
+                error += dx;
+                current.y += step.y;
+            }
+            Some(point)
+        })
+    }
+}
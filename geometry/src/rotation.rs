@@ -0,0 +1,80 @@
+use crate::Point3;
+
+/// One of the 24 rotations that map the coordinate axes onto coordinate axes.
+///
+/// Stored as a matrix whose rows each contain a single entry of 1 or -1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    matrix: [[i64; 3]; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    /// All 24 rotations, starting with the identity.
+    pub fn all() -> Vec<Rotation> {
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        let mut rotations = Vec::with_capacity(24);
+        for permutation in &PERMUTATIONS {
+            for signs in 0..8 {
+                let mut matrix = [[0; 3]; 3];
+                for (row, &axis) in permutation.iter().enumerate() {
+                    matrix[row][axis] = if signs >> row & 1 == 1 { -1 } else { 1 };
+                }
+                let rotation = Rotation { matrix };
+                // The other half are reflections
+                if rotation.determinant() == 1 {
+                    rotations.push(rotation);
+                }
+            }
+        }
+        rotations
+    }
+
+    fn determinant(&self) -> i64 {
+        let m = &self.matrix;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    pub fn apply(&self, p: Point3) -> Point3 {
+        let c = p.coordinates();
+        let mut rotated = [0; 3];
+        for (r, row) in rotated.iter_mut().zip(&self.matrix) {
+            *r = row.iter().zip(&c).map(|(m, c)| m * c).sum();
+        }
+        Point3::from_coordinates(rotated)
+    }
+
+    /// The rotation that first applies `first` and then `self`.
+    pub fn after(&self, first: &Rotation) -> Rotation {
+        let mut matrix = [[0; 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = (0..3).map(|k| self.matrix[i][k] * first.matrix[k][j]).sum();
+            }
+        }
+        Rotation { matrix }
+    }
+
+    pub fn inverse(&self) -> Rotation {
+        // Rotation matrices are orthogonal, so the inverse is the transpose
+        let mut matrix = [[0; 3]; 3];
+        for (i, row) in self.matrix.iter().enumerate() {
+            for (j, &entry) in row.iter().enumerate() {
+                matrix[j][i] = entry;
+            }
+        }
+        Rotation { matrix }
+    }
+}
@@ -0,0 +1,112 @@
+use std::convert::TryInto;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A delimiter-separated line whose fields could not be parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FieldError<E> {
+    Count {
+        expected: usize,
+        found: usize,
+    },
+    /// The field at the 0-based `index` is malformed
+    Parse {
+        index: usize,
+        text: String,
+        error: E,
+    },
+}
+
+impl<E: Display> Display for FieldError<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Count { expected, found } => {
+                write!(f, "expected {} fields, found {}", expected, found)
+            }
+            Self::Parse { index, text, error } => {
+                write!(f, "field {} ({:?}): {}", index + 1, text, error)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for FieldError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Count { .. } => None,
+            Self::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+fn parse_fields<'a, T: FromStr, I: Iterator<Item = &'a str>>(
+    fields: I,
+) -> Result<Vec<T>, FieldError<T::Err>> {
+    fields
+        .map(str::trim)
+        .enumerate()
+        .map(|(index, text)| {
+            text.parse().map_err(|error| FieldError::Parse {
+                index,
+                text: String::from(text),
+                error,
+            })
+        })
+        .collect()
+}
+
+/// Parses the `sep`-separated, trimmed fields of `s`. An empty line yields an empty list.
+pub fn split_list<T: FromStr>(s: &str, sep: &str) -> Result<Vec<T>, FieldError<T::Err>> {
+    if s.trim().is_empty() {
+        Ok(Vec::new())
+    } else {
+        parse_fields(s.split(sep))
+    }
+}
+
+/// Parses the fields of `s` separated by runs of whitespace.
+pub fn split_whitespace_list<T: FromStr>(s: &str) -> Result<Vec<T>, FieldError<T::Err>> {
+    parse_fields(s.split_whitespace())
+}
+
+/// Parses exactly `N` `sep`-separated, trimmed fields of `s`.
+pub fn split_array<T: FromStr, const N: usize>(
+    s: &str,
+    sep: &str,
+) -> Result<[T; N], FieldError<T::Err>> {
+    let fields: Vec<&str> = s.split(sep).collect();
+    if fields.len() != N {
+        return Err(FieldError::Count {
+            expected: N,
+            found: fields.len(),
+        });
+    }
+    let fields: Vec<T> = parse_fields(fields.into_iter())?;
+    Ok(fields
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("field count was checked")))
+}
+
+/// Parses exactly two `sep`-separated, trimmed fields of `s` into values of different types.
+pub fn split_pair<A, B, E>(s: &str, sep: &str) -> Result<(A, B), FieldError<E>>
+where
+    A: FromStr<Err = E>,
+    B: FromStr<Err = E>,
+{
+    let fields: Vec<&str> = s.split(sep).map(str::trim).collect();
+    if fields.len() != 2 {
+        return Err(FieldError::Count {
+            expected: 2,
+            found: fields.len(),
+        });
+    }
+    let field_error = |index: usize| {
+        let text = String::from(fields[index]);
+        move |error| FieldError::Parse { index, text, error }
+    };
+    Ok((
+        fields[0].parse().map_err(field_error(0))?,
+        fields[1].parse().map_err(field_error(1))?,
+    ))
+}
@@ -0,0 +1,382 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// Conversion of a single character of a character grid into a cell value.
+pub trait FromCell: Sized {
+    type Err;
+
+    fn from_cell(c: char) -> Result<Self, Self::Err>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseCellError(pub char);
+
+impl Display for ParseCellError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "unexpected cell {:?}", self.0)
+    }
+}
+
+impl Error for ParseCellError {}
+
+impl FromCell for char {
+    type Err = Infallible;
+
+    fn from_cell(c: char) -> Result<Self, Self::Err> {
+        Ok(c)
+    }
+}
+
+impl FromCell for u8 {
+    type Err = ParseCellError;
+
+    fn from_cell(c: char) -> Result<Self, Self::Err> {
+        c.to_digit(10).map(|d| d as u8).ok_or(ParseCellError(c))
+    }
+}
+
+impl FromCell for u32 {
+    type Err = ParseCellError;
+
+    fn from_cell(c: char) -> Result<Self, Self::Err> {
+        c.to_digit(10).ok_or(ParseCellError(c))
+    }
+}
+
+impl FromCell for bool {
+    type Err = ParseCellError;
+
+    fn from_cell(c: char) -> Result<Self, Self::Err> {
+        match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            c => Err(ParseCellError(c)),
+        }
+    }
+}
+
+/// Position of a cell as (row, column), may lie outside the grid for padded edges.
+pub type Position = (isize, isize);
+
+/// Determines what lies beyond the borders of a grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Edges<T> {
+    /// There is nothing outside the grid
+    Bounded,
+    /// The grid repeats in every direction (toroidal)
+    Wrapping,
+    /// Every cell outside the grid has the given value
+    Padded(T),
+}
+
+const NEIGHBORS_4: [Position; 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const NEIGHBORS_8: [Position; 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    height: usize,
+    width: usize,
+    edges: Edges<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridShapeError> {
+        let height = rows.len();
+        let width = rows.first().map(|r| r.len()).unwrap_or(0);
+        if let Some((row, r)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(GridShapeError {
+                row,
+                expected: width,
+                found: r.len(),
+            });
+        }
+        Ok(Grid {
+            data: rows.into_iter().flatten().collect(),
+            height,
+            width,
+            edges: Edges::Bounded,
+        })
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(height: usize, width: usize, mut f: F) -> Self {
+        let data = (0..height)
+            .flat_map(|i| (0..width).map(move |j| (i, j)))
+            .map(|(i, j)| f(i, j))
+            .collect();
+        Grid {
+            data,
+            height,
+            width,
+            edges: Edges::Bounded,
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, ParseGridError<T::Err>>
+    where
+        T: FromCell,
+    {
+        let rows = s
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .enumerate()
+            .map(|(row, l)| {
+                l.trim_end_matches('\r')
+                    .chars()
+                    .enumerate()
+                    .map(|(col, c)| {
+                        T::from_cell(c).map_err(|error| ParseGridError::Cell { row, col, error })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_rows(rows)?)
+    }
+
+    pub fn with_edges(self, edges: Edges<T>) -> Self {
+        Grid { edges, ..self }
+    }
+
+    pub fn edges(&self) -> &Edges<T> {
+        &self.edges
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Maps a position to the cell it refers to under the edge policy, if it lies on the grid.
+    pub fn resolve(&self, (i, j): Position) -> Option<(usize, usize)> {
+        match self.edges {
+            Edges::Wrapping if !self.is_empty() => Some((
+                i.rem_euclid(self.height as isize) as usize,
+                j.rem_euclid(self.width as isize) as usize,
+            )),
+            _ => {
+                if i >= 0 && j >= 0 && (i as usize) < self.height && (j as usize) < self.width {
+                    Some((i as usize, j as usize))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        match self.resolve(pos) {
+            Some(idx) => Some(&self[idx]),
+            None => match &self.edges {
+                Edges::Padded(padding) => Some(padding),
+                _ => None,
+            },
+        }
+    }
+
+    /// Mutable access to a cell, the padding of a padded grid cannot be modified.
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.resolve(pos).map(move |idx| &mut self[idx])
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> &mut [T] {
+        &mut self.data[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn col(&self, j: usize) -> impl Iterator<Item = &T> {
+        assert!(j < self.width, "column {} out of bounds", j);
+        self.data.iter().skip(j).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics for a chunk size of 0
+        self.data.chunks(self.width.max(1))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(idx, v)| ((idx / width, idx % width), v))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let width = self.width;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(idx, v)| ((idx / width, idx % width), v))
+    }
+
+    /// Horizontally and vertically adjacent cells.
+    pub fn neighbors4(&self, pos: Position) -> Neighbors<'_, T> {
+        Neighbors {
+            grid: self,
+            center: pos,
+            offsets: &NEIGHBORS_4,
+        }
+    }
+
+    /// Horizontally, vertically and diagonally adjacent cells.
+    pub fn neighbors8(&self, pos: Position) -> Neighbors<'_, T> {
+        Neighbors {
+            grid: self,
+            center: pos,
+            offsets: &NEIGHBORS_8,
+        }
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, mut f: F) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(&mut f).collect(),
+            height: self.height,
+            width: self.width,
+            edges: match &self.edges {
+                Edges::Bounded => Edges::Bounded,
+                Edges::Wrapping => Edges::Wrapping,
+                Edges::Padded(padding) => Edges::Padded(f(padding)),
+            },
+        }
+    }
+}
+
+impl<T: Default> Grid<T> {
+    /// Pads the grid with the default value of the cell type.
+    pub fn padded(self) -> Self {
+        self.with_edges(Edges::Padded(T::default()))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        assert!(j < self.width, "column {} out of bounds", j);
+        &self.data[i * self.width + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        assert!(j < self.width, "column {} out of bounds", j);
+        &mut self.data[i * self.width + j]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for v in row {
+                write!(f, "{}", v)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the neighbors of a cell that respects the edge policy of the grid.
+pub struct Neighbors<'a, T> {
+    grid: &'a Grid<T>,
+    center: Position,
+    offsets: &'static [Position],
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = (Position, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((&(di, dj), rest)) = self.offsets.split_first() {
+            self.offsets = rest;
+            let pos = (self.center.0 + di, self.center.1 + dj);
+            let grid = self.grid;
+            if let Some(idx) = grid.resolve(pos) {
+                return Some(((idx.0 as isize, idx.1 as isize), &grid[idx]));
+            } else if let Edges::Padded(padding) = &grid.edges {
+                return Some((pos, padding));
+            }
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridShapeError {
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Display for GridShapeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "row {} has {} cells, expected {}",
+            self.row + 1,
+            self.found,
+            self.expected
+        )
+    }
+}
+
+impl Error for GridShapeError {}
+
+#[derive(Debug)]
+pub enum ParseGridError<E> {
+    Shape(GridShapeError),
+    Cell { row: usize, col: usize, error: E },
+}
+
+impl<E> From<GridShapeError> for ParseGridError<E> {
+    fn from(e: GridShapeError) -> Self {
+        Self::Shape(e)
+    }
+}
+
+impl<E: Display> Display for ParseGridError<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Shape(e) => write!(f, "{}", e),
+            Self::Cell { row, col, error } => {
+                write!(f, "row {}, column {}: {}", row + 1, col + 1, error)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseGridError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Shape(e) => Some(e),
+            Self::Cell { error, .. } => Some(error),
+        }
+    }
+}
@@ -1,15 +1,263 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
 use std::fs::File;
-use std::io::{self, BufRead};
+use std::io::{self, BufRead, BufReader, Lines};
+use std::marker::PhantomData;
 use std::str::FromStr;
 
+mod fields;
+mod grid;
+mod pattern;
+mod sections;
+mod source;
+
+pub use fields::{split_array, split_list, split_pair, split_whitespace_list, FieldError};
+pub use grid::{
+    Edges, FromCell, Grid, GridShapeError, Neighbors, ParseCellError, ParseGridError, Position,
+};
+pub use input_parser_derive::FromPattern;
+pub use pattern::{PatternError, PatternErrorKind, PatternMatcher};
+pub use sections::{ParseSectionError, Section, SectionError, Sections};
+pub use source::InputSource;
+
+/// A line that could not be parsed, together with its 1-based line number and raw text.
+#[derive(Debug)]
+pub struct LineError<E> {
+    pub line: usize,
+    pub text: String,
+    pub error: E,
+}
+
+impl<E: Display> Display for LineError<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "line {} ({:?}): {}", self.line, self.text, self.error)
+    }
+}
+
+impl<E: Error + 'static> Error for LineError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseInputsError<E> {
+    Io(io::Error),
+    Lines(Vec<LineError<E>>),
+}
+
+impl<E> From<io::Error> for ParseInputsError<E> {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl<E: Display> Display for ParseInputsError<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "error reading input: {}", e),
+            Self::Lines(errors) => {
+                write!(f, "{} line(s) could not be parsed", errors.len())?;
+                for e in errors {
+                    write!(f, "\n  {}", e)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug + Display> Error for ParseInputsError<E> {}
+
+/// Result of lenient parsing: all records that could be parsed and the lines that were skipped.
+#[derive(Debug)]
+pub struct ParseReport<T, E> {
+    pub records: Vec<T>,
+    pub skipped: Vec<LineError<E>>,
+}
+
+impl<T, E> ParseReport<T, E> {
+    pub fn is_complete(&self) -> bool {
+        self.skipped.is_empty()
+    }
+
+    /// Turns the report into an error if any line was skipped.
+    pub fn into_result(self) -> Result<Vec<T>, ParseInputsError<E>> {
+        if self.is_complete() {
+            Ok(self.records)
+        } else {
+            Err(ParseInputsError::Lines(self.skipped))
+        }
+    }
+}
+
+impl<T: FromStr> ParseReport<T, T::Err> {
+    /// Consumes all lines, aborting only if a line cannot be read.
+    pub fn collect<R: BufRead>(lines: ParsedLines<T, R>) -> io::Result<Self> {
+        let mut report = ParseReport {
+            records: Vec::new(),
+            skipped: Vec::new(),
+        };
+        for parsed in lines {
+            match parsed {
+                Ok(record) => report.records.push(record),
+                Err(StreamError::Parse(e)) => report.skipped.push(e),
+                Err(StreamError::Io { error, .. }) => return Err(error),
+            }
+        }
+        Ok(report)
+    }
+}
+
 pub fn parse_inputs<T: FromStr>(filename: &str) -> io::Result<Vec<T>> {
     let file = File::open(filename)?;
-    let lines = io::BufReader::new(file).lines();
-    Ok(
-        lines
-        .filter_map(|s| {
-            s.ok().and_then(|s| s.parse::<T>().ok())
+    let lines = BufReader::new(file).lines();
+    Ok(lines
+        .filter_map(|s| s.ok().and_then(|s| s.parse::<T>().ok()))
+        .collect())
+}
+
+/// Parses every line of `filename` and fails with all offending lines if any of them is malformed.
+pub fn parse_inputs_strict<T: FromStr>(filename: &str) -> Result<Vec<T>, ParseInputsError<T::Err>> {
+    parse_inputs_lenient(filename)?.into_result()
+}
+
+/// Parses every line of `filename`, skipping malformed lines but reporting them alongside the records.
+pub fn parse_inputs_lenient<T: FromStr>(filename: &str) -> io::Result<ParseReport<T, T::Err>> {
+    ParseReport::collect(stream_inputs(filename)?)
+}
+
+/// Parses every line of an in-memory input and fails with all offending lines if any of them is malformed.
+pub fn parse_str<T: FromStr>(input: &str) -> Result<Vec<T>, ParseInputsError<T::Err>> {
+    ParseReport::collect(parse_lines(input.as_bytes()))?.into_result()
+}
+
+/// Lazily parses the lines of `filename`, see [`parse_lines`].
+pub fn stream_inputs<T: FromStr>(filename: &str) -> io::Result<ParsedLines<T, BufReader<File>>> {
+    Ok(parse_lines(BufReader::new(File::open(filename)?)))
+}
+
+/// Lazily parses every line read from `reader`, so only the current line is kept in memory.
+///
+/// A blank last line ends the input like a final newline and is not parsed as a record.
+pub fn parse_lines<T: FromStr, R: BufRead>(reader: R) -> ParsedLines<T, R> {
+    ParsedLines {
+        lines: reader.lines(),
+        ahead: None,
+        line: 0,
+        _record: PhantomData,
+    }
+}
+
+/// Iterator returned by [`parse_lines`] that yields one parse result per line.
+pub struct ParsedLines<T, R> {
+    lines: Lines<R>,
+    /// Line read after a blank one to find out whether the blank line is the last
+    ahead: Option<io::Result<String>>,
+    line: usize,
+    _record: PhantomData<fn() -> T>,
+}
+
+impl<T: FromStr, R: BufRead> Iterator for ParsedLines<T, R> {
+    type Item = Result<T, StreamError<T::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = match self.ahead.take() {
+            Some(text) => text,
+            None => self.lines.next()?,
+        };
+        if matches!(&text, Ok(t) if t.is_empty()) {
+            self.ahead = Some(self.lines.next()?);
+        }
+        self.line += 1;
+        let line = self.line;
+        Some(match text {
+            Ok(text) => text
+                .parse::<T>()
+                .map_err(|error| StreamError::Parse(LineError { line, text, error })),
+            Err(error) => Err(StreamError::Io { line, error }),
         })
-        .collect()
-    )
+    }
+}
+
+/// Error of a streamed line, which either could not be read or could not be parsed.
+#[derive(Debug)]
+pub enum StreamError<E> {
+    Io { line: usize, error: io::Error },
+    Parse(LineError<E>),
+}
+
+impl<E: Display> Display for StreamError<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Io { line, error } => write!(f, "line {}: error reading input: {}", line, error),
+            Self::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for StreamError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    /// Reader that fails the test if it is ever read from
+    struct Unreachable;
+
+    impl Read for Unreachable {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            panic!("read past the first error")
+        }
+    }
+
+    impl BufRead for Unreachable {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            panic!("read past the first error")
+        }
+
+        fn consume(&mut self, _amt: usize) {}
+    }
+
+    #[test]
+    fn parse_lines_numbers_lines() {
+        let parsed: Vec<_> = parse_lines::<i32, _>("1\nx\n3\n".as_bytes()).collect();
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed[0].as_ref().unwrap(), &1);
+        match &parsed[1] {
+            Err(StreamError::Parse(e)) => {
+                assert_eq!(e.line, 2);
+                assert_eq!(e.text, "x");
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert_eq!(parsed[2].as_ref().unwrap(), &3);
+    }
+
+    #[test]
+    fn parse_str_accepts_trailing_blank_line() {
+        let depths = parse_str::<i32>("199\n200\n208\n\n").unwrap();
+        assert_eq!(depths, [199, 200, 208]);
+        assert!(parse_str::<i32>("199\n\n200\n").is_err());
+    }
+
+    #[test]
+    fn parse_lines_is_lazy() {
+        let reader = "1\nx\n".as_bytes().chain(Unreachable);
+        let mut lines = parse_lines::<i32, _>(reader);
+        assert_eq!(lines.next().unwrap().unwrap(), 1);
+        let stopped_at = lines.find_map(|line| line.err());
+        match stopped_at {
+            Some(StreamError::Parse(e)) => assert_eq!(e.line, 2),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
 }
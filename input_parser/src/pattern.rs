@@ -0,0 +1,137 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Error of a `FromStr` implementation generated by `#[derive(FromPattern)]`.
+#[derive(Debug)]
+pub struct PatternError {
+    pub input: String,
+    /// Byte offset into the trimmed input at which matching failed
+    pub position: usize,
+    pub kind: PatternErrorKind,
+}
+
+#[derive(Debug)]
+pub enum PatternErrorKind {
+    Literal {
+        expected: &'static str,
+    },
+    Field {
+        name: &'static str,
+        text: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    Trailing,
+}
+
+impl PatternError {
+    /// Keeps the error that got further into the input, used to choose between enum variants.
+    pub fn furthest(self, other: PatternError) -> PatternError {
+        if other.position > self.position {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?} at position {}: ", self.input, self.position)?;
+        match &self.kind {
+            PatternErrorKind::Literal { expected } => write!(f, "expected {:?}", expected),
+            PatternErrorKind::Field { name, text, source } => {
+                write!(f, "field {} ({:?}): {}", name, text, source)
+            }
+            PatternErrorKind::Trailing => write!(f, "unexpected trailing input"),
+        }
+    }
+}
+
+impl Error for PatternError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            PatternErrorKind::Field { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Matches an input against a pattern piece by piece, used by `#[derive(FromPattern)]`.
+pub struct PatternMatcher<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> PatternMatcher<'a> {
+    pub fn new(input: &'a str) -> Self {
+        PatternMatcher {
+            input: input.trim(),
+            position: 0,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn error(&self, position: usize, kind: PatternErrorKind) -> PatternError {
+        PatternError {
+            input: String::from(self.input),
+            position,
+            kind,
+        }
+    }
+
+    pub fn literal(&mut self, expected: &'static str) -> Result<(), PatternError> {
+        if self.rest().starts_with(expected) {
+            self.position += expected.len();
+            Ok(())
+        } else {
+            Err(self.error(self.position, PatternErrorKind::Literal { expected }))
+        }
+    }
+
+    /// Parses the text up to the next occurrence of `until` (or the end of the input) as a field.
+    pub fn field<T>(
+        &mut self,
+        name: &'static str,
+        until: Option<&'static str>,
+    ) -> Result<T, PatternError>
+    where
+        T: FromStr,
+        T::Err: Error + Send + Sync + 'static,
+    {
+        let rest = self.rest();
+        let len = match until {
+            Some(literal) => rest.find(literal).ok_or_else(|| {
+                self.error(
+                    self.input.len(),
+                    PatternErrorKind::Literal { expected: literal },
+                )
+            })?,
+            None => rest.len(),
+        };
+        let text = &rest[..len];
+        let value = text.trim().parse().map_err(|e| {
+            self.error(
+                self.position,
+                PatternErrorKind::Field {
+                    name,
+                    text: String::from(text),
+                    source: Box::new(e),
+                },
+            )
+        })?;
+        self.position += len;
+        Ok(value)
+    }
+
+    pub fn finish(self) -> Result<(), PatternError> {
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(self.position, PatternErrorKind::Trailing))
+        }
+    }
+}
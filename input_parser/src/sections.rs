@@ -0,0 +1,231 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::str::FromStr;
+
+use crate::LineError;
+
+/// A block of consecutive non-blank lines.
+#[derive(Clone, Debug)]
+pub struct Section {
+    /// 0-based position of the section in the input
+    pub index: usize,
+    /// 1-based line number of the first line in the section
+    pub first_line: usize,
+    lines: Vec<String>,
+}
+
+impl Section {
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Parses a section that consists of exactly one line.
+    pub fn single<T: FromStr>(&self) -> Result<T, ParseSectionError<T::Err>> {
+        self.expect_lines(1)?;
+        self.parse_line(0, |s| s.parse())
+    }
+
+    /// Parses every line of the section.
+    pub fn parse<T: FromStr>(&self) -> Result<Vec<T>, ParseSectionError<T::Err>> {
+        self.parse_with(|s| s.parse())
+    }
+
+    /// Parses every line of the section with a custom parse function.
+    pub fn parse_with<T, E, F: Fn(&str) -> Result<T, E>>(
+        &self,
+        f: F,
+    ) -> Result<Vec<T>, ParseSectionError<E>> {
+        (0..self.lines.len())
+            .map(|idx| self.parse_line(idx, &f))
+            .collect()
+    }
+
+    pub fn expect_lines(&self, expected: usize) -> Result<&[String], SectionError> {
+        if self.lines.len() != expected {
+            Err(SectionError::Arity {
+                section: self.index,
+                first_line: self.first_line,
+                expected,
+                found: self.lines.len(),
+            })
+        } else {
+            Ok(&self.lines)
+        }
+    }
+
+    fn parse_line<T, E, F: Fn(&str) -> Result<T, E>>(
+        &self,
+        idx: usize,
+        f: F,
+    ) -> Result<T, ParseSectionError<E>> {
+        f(&self.lines[idx]).map_err(|error| {
+            ParseSectionError::Line(LineError {
+                line: self.first_line + idx,
+                text: self.lines[idx].clone(),
+                error,
+            })
+        })
+    }
+}
+
+/// An input split into blank-line-separated sections that are consumed front to back.
+#[derive(Debug)]
+pub struct Sections {
+    sections: VecDeque<Section>,
+    consumed: usize,
+}
+
+impl Sections {
+    pub fn read(filename: &str) -> io::Result<Self> {
+        Ok(Self::from(fs::read_to_string(filename)?.as_str()))
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.sections.len()
+    }
+
+    pub fn next_section(&mut self) -> Result<Section, SectionError> {
+        let section = self.sections.pop_front().ok_or(SectionError::Missing {
+            section: self.consumed,
+        })?;
+        self.consumed += 1;
+        Ok(section)
+    }
+
+    /// Parses the next section as a single header line.
+    pub fn header<T: FromStr>(&mut self) -> Result<T, ParseSectionError<T::Err>> {
+        self.next_section()?.single()
+    }
+
+    /// Parses every line of the next section.
+    pub fn records<T: FromStr>(&mut self) -> Result<Vec<T>, ParseSectionError<T::Err>> {
+        self.next_section()?.parse()
+    }
+
+    /// Fails if there are sections left that were not consumed.
+    pub fn finish(self) -> Result<(), SectionError> {
+        match self.sections.front() {
+            Some(section) => Err(SectionError::Trailing {
+                section: section.index,
+                first_line: section.first_line,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<&str> for Sections {
+    fn from(s: &str) -> Self {
+        let mut sections = VecDeque::new();
+        let mut current: Option<Section> = None;
+        for (idx, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                sections.extend(current.take());
+            } else {
+                current
+                    .get_or_insert_with(|| Section {
+                        index: sections.len(),
+                        first_line: idx + 1,
+                        lines: Vec::new(),
+                    })
+                    .lines
+                    .push(String::from(line));
+            }
+        }
+        sections.extend(current);
+        Sections {
+            sections,
+            consumed: 0,
+        }
+    }
+}
+
+impl Iterator for Sections {
+    type Item = Section;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_section().ok()
+    }
+}
+
+#[derive(Debug)]
+pub enum SectionError {
+    Missing {
+        section: usize,
+    },
+    Arity {
+        section: usize,
+        first_line: usize,
+        expected: usize,
+        found: usize,
+    },
+    Trailing {
+        section: usize,
+        first_line: usize,
+    },
+}
+
+impl Display for SectionError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Missing { section } => write!(f, "section {} is missing", section + 1),
+            Self::Arity {
+                section,
+                first_line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "section {} (line {}) has {} line(s), expected {}",
+                section + 1,
+                first_line,
+                found,
+                expected
+            ),
+            Self::Trailing {
+                section,
+                first_line,
+            } => write!(
+                f,
+                "unexpected section {} starting at line {}",
+                section + 1,
+                first_line
+            ),
+        }
+    }
+}
+
+impl Error for SectionError {}
+
+#[derive(Debug)]
+pub enum ParseSectionError<E> {
+    Section(SectionError),
+    Line(LineError<E>),
+}
+
+impl<E> From<SectionError> for ParseSectionError<E> {
+    fn from(e: SectionError) -> Self {
+        Self::Section(e)
+    }
+}
+
+impl<E: Display> Display for ParseSectionError<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Section(e) => write!(f, "{}", e),
+            Self::Line(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseSectionError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Section(e) => Some(e),
+            Self::Line(e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::{parse_lines, ParseInputsError, ParseReport, ParsedLines, Sections};
+
+/// Where the puzzle input of a solution comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+    /// Input compiled into the binary, usually via `include_str!`
+    Embedded(&'static str),
+}
+
+impl InputSource {
+    pub fn path<P: Into<PathBuf>>(path: P) -> Self {
+        Self::Path(path.into())
+    }
+
+    /// Uses the first command line argument as input path (`-` for stdin) and `default` if there is none.
+    pub fn from_args_or(default: InputSource) -> Self {
+        Self::from_arg(env::args().nth(1), default)
+    }
+
+    pub fn from_arg(arg: Option<String>, default: InputSource) -> Self {
+        match arg.as_deref() {
+            None => default,
+            Some("-") => Self::Stdin,
+            Some(path) => Self::path(path),
+        }
+    }
+
+    pub fn reader(&self) -> io::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Self::Path(path) => Box::new(BufReader::new(File::open(path)?)),
+            Self::Stdin => Box::new(io::stdin().lock()),
+            Self::Embedded(s) => Box::new(s.as_bytes()),
+        })
+    }
+
+    pub fn read_to_string(&self) -> io::Result<String> {
+        match self {
+            Self::Path(path) => fs::read_to_string(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Self::Embedded(s) => Ok(String::from(*s)),
+        }
+    }
+
+    /// Lazily parses the input line by line, see [`parse_lines`].
+    pub fn stream<T: FromStr>(&self) -> io::Result<ParsedLines<T, Box<dyn BufRead>>> {
+        Ok(parse_lines(self.reader()?))
+    }
+
+    /// Parses every line and fails with all offending lines if any of them is malformed.
+    pub fn parse_strict<T: FromStr>(&self) -> Result<Vec<T>, ParseInputsError<T::Err>> {
+        self.parse_lenient()?.into_result()
+    }
+
+    /// Parses every line, skipping malformed lines but reporting them alongside the records.
+    pub fn parse_lenient<T: FromStr>(&self) -> io::Result<ParseReport<T, T::Err>> {
+        ParseReport::collect(self.stream()?)
+    }
+
+    pub fn sections(&self) -> io::Result<Sections> {
+        Ok(Sections::from(self.read_to_string()?.as_str()))
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::Embedded(_) => write!(f, "<embedded>"),
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::HashSet;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, Ident, LitStr, Result, Type,
+};
+
+/// Derives `FromStr` from a textual template given by `#[pattern("...")]`.
+///
+/// Fields are referenced by name (`{x}`) or position (`{0}`) and parsed with their own `FromStr`
+/// implementation, everything else has to match literally and `{{` / `}}` escape braces.
+/// Enums need a pattern on every variant, the first variant that matches is used.
+/// Errors are reported as `input_parser::PatternError`.
+#[proc_macro_derive(FromPattern, attributes(pattern))]
+pub fn derive_from_pattern(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+enum Segment {
+    Literal(String),
+    Field(String),
+}
+
+fn parse_pattern(pattern: &LitStr) -> Result<Vec<Segment>> {
+    let value = pattern.value();
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(Error::new(pattern.span(), "unclosed `{` in pattern")),
+                    }
+                }
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                } else if matches!(segments.last(), Some(Segment::Field(_))) {
+                    return Err(Error::new(
+                        pattern.span(),
+                        "fields in a pattern must be separated by a literal",
+                    ));
+                }
+                segments.push(Segment::Field(String::from(name.trim())));
+            }
+            '}' => return Err(Error::new(pattern.span(), "unmatched `}` in pattern")),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+    Ok(segments)
+}
+
+fn pattern_attr(attrs: &[Attribute], span: Span) -> Result<LitStr> {
+    attrs
+        .iter()
+        .find(|a| a.path.is_ident("pattern"))
+        .ok_or_else(|| Error::new(span, "missing #[pattern(\"...\")] attribute"))?
+        .parse_args()
+}
+
+/// Generates an expression that matches the pattern and evaluates to a `Result` of `constructor`.
+fn expand_matcher(
+    pattern: &LitStr,
+    fields: &Fields,
+    constructor: TokenStream2,
+) -> Result<TokenStream2> {
+    let segments = parse_pattern(pattern)?;
+    let field_list: Vec<(String, Ident, &Type)> = match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .filter_map(|f| f.ident.as_ref().map(|id| (id, &f.ty)))
+            .map(|(id, ty)| (id.to_string(), format_ident!("__field_{}", id), ty))
+            .collect(),
+        Fields::Unnamed(unnamed) => unnamed
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (i.to_string(), format_ident!("__field_{}", i), &f.ty))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let mut steps = Vec::new();
+    let mut used = HashSet::new();
+    for (idx, segment) in segments.iter().enumerate() {
+        match segment {
+            Segment::Literal(literal) => steps.push(quote! { __matcher.literal(#literal)?; }),
+            Segment::Field(name) => {
+                let (_, binding, ty) =
+                    field_list
+                        .iter()
+                        .find(|(n, _, _)| n == name)
+                        .ok_or_else(|| {
+                            Error::new(
+                                pattern.span(),
+                                format!("unknown field `{}` in pattern", name),
+                            )
+                        })?;
+                if !used.insert(name.clone()) {
+                    return Err(Error::new(
+                        pattern.span(),
+                        format!("field `{}` appears more than once in pattern", name),
+                    ));
+                }
+                let until = match segments.get(idx + 1) {
+                    Some(Segment::Literal(literal)) => {
+                        quote! { ::std::option::Option::Some(#literal) }
+                    }
+                    _ => quote! { ::std::option::Option::None },
+                };
+                steps.push(quote! { let #binding: #ty = __matcher.field(#name, #until)?; });
+            }
+        }
+    }
+    if let Some((name, _, _)) = field_list.iter().find(|(n, _, _)| !used.contains(n)) {
+        return Err(Error::new(
+            pattern.span(),
+            format!("field `{}` is missing from pattern", name),
+        ));
+    }
+
+    let bindings = field_list.iter().map(|(_, binding, _)| binding);
+    let construct = match fields {
+        Fields::Named(_) => {
+            let names = field_list
+                .iter()
+                .map(|(name, _, _)| format_ident!("{}", name));
+            quote! { #constructor { #(#names: #bindings),* } }
+        }
+        Fields::Unnamed(_) => quote! { #constructor(#(#bindings),*) },
+        Fields::Unit => quote! { #constructor },
+    };
+    Ok(quote! {
+        (|| -> ::std::result::Result<Self, ::input_parser::PatternError> {
+            let mut __matcher = ::input_parser::PatternMatcher::new(__input);
+            #(#steps)*
+            __matcher.finish()?;
+            ::std::result::Result::Ok(#construct)
+        })()
+    })
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let pattern = pattern_attr(&input.attrs, name.span())?;
+            expand_matcher(&pattern, &data.fields, quote! { #name })?
+        }
+        Data::Enum(data) => {
+            if data.variants.is_empty() {
+                return Err(Error::new(
+                    name.span(),
+                    "FromPattern cannot be derived for enums without variants",
+                ));
+            }
+            let attempts = data
+                .variants
+                .iter()
+                .map(|v| {
+                    let pattern = pattern_attr(&v.attrs, v.ident.span())?;
+                    let variant = &v.ident;
+                    expand_matcher(&pattern, &v.fields, quote! { #name::#variant })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            quote! {
+                let mut __error: ::std::option::Option<::input_parser::PatternError> =
+                    ::std::option::Option::None;
+                #(
+                    match #attempts {
+                        ::std::result::Result::Ok(v) => return ::std::result::Result::Ok(v),
+                        ::std::result::Result::Err(e) => {
+                            __error = ::std::option::Option::Some(match __error {
+                                ::std::option::Option::Some(prev) => prev.furthest(e),
+                                ::std::option::Option::None => e,
+                            })
+                        }
+                    }
+                )*
+                ::std::result::Result::Err(__error.expect("enum has at least one variant"))
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                name.span(),
+                "FromPattern cannot be derived for unions",
+            ))
+        }
+    };
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::input_parser::PatternError;
+
+            fn from_str(__input: &str) -> ::std::result::Result<Self, Self::Err> {
+                #body
+            }
+        }
+    })
+}
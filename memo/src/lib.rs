@@ -0,0 +1,163 @@
+//! Memo tables for recursive solvers that count how often they save a computation.
+//!
+//! A [`Memo`] reports its statistics when it is dropped, so the runner can collect the
+//! statistics of all tables used by a part with [`take_reports`] after the part has finished.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+thread_local! {
+    static REPORTS: RefCell<Vec<(&'static str, MemoStats)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// How a memo table was used.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    /// Lookups answered from the table
+    pub hits: usize,
+    /// Lookups that had to be computed
+    pub misses: usize,
+    /// Values stored in the table
+    pub entries: usize,
+}
+
+impl MemoStats {
+    pub fn lookups(&self) -> usize {
+        self.hits + self.misses
+    }
+
+    /// Fraction of lookups answered from the table, 0 if there were none.
+    pub fn hit_rate(&self) -> f64 {
+        if self.lookups() == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.lookups() as f64
+        }
+    }
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} entries",
+            self.hits,
+            self.misses,
+            100.0 * self.hit_rate(),
+            self.entries
+        )
+    }
+}
+
+/// Cached results of a function, keyed on its arguments.
+///
+/// The lookup and the insertion are separate steps so the value can be computed with
+/// recursive calls that borrow the owner of the table mutably, see [`memoize!`].
+pub struct Memo<K, V> {
+    name: &'static str,
+    table: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    /// Creates an empty table, `name` identifies it in the reported statistics.
+    pub fn new(name: &'static str) -> Self {
+        Memo {
+            name,
+            table: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Looks up the cached value for `key` and counts the lookup as a hit or a miss.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.table.get(key).cloned();
+        if value.is_some() {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        value
+    }
+
+    /// Caches `value` for `key` and returns it.
+    pub fn insert(&mut self, key: K, value: V) -> V {
+        self.table.insert(key, value.clone());
+        value
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.table.len(),
+        }
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        if self.hits + self.misses > 0 {
+            let stats = MemoStats {
+                hits: self.hits,
+                misses: self.misses,
+                entries: self.table.len(),
+            };
+            REPORTS.with(|reports| reports.borrow_mut().push((self.name, stats)));
+        }
+    }
+}
+
+/// Statistics of the used tables dropped on this thread since the last call, in drop order.
+///
+/// Tables with the same name are merged.
+pub fn take_reports() -> Vec<(&'static str, MemoStats)> {
+    let reports = REPORTS.with(|reports| reports.replace(Vec::new()));
+    let mut merged: Vec<(&'static str, MemoStats)> = Vec::new();
+    for (name, stats) in reports {
+        match merged.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, total)) => {
+                total.hits += stats.hits;
+                total.misses += stats.misses;
+                total.entries += stats.entries;
+            }
+            None => merged.push((name, stats)),
+        }
+    }
+    merged
+}
+
+/// Returns the value cached in `$memo` for `$key`, or evaluates `$body`, caches and returns it.
+///
+/// The table is only borrowed for the lookup and the insertion, so `$body` may recurse through
+/// the struct that owns the table.
+///
+/// ```ignore
+/// fn fib(&mut self, n: u64) -> u64 {
+///     if n < 2 {
+///         return n;
+///     }
+///     memoize!(self.fibs, n, self.fib(n - 1) + self.fib(n - 2))
+/// }
+/// ```
+#[macro_export]
+macro_rules! memoize {
+    ($memo:expr, $key:expr, $body:expr) => {{
+        let key = $key;
+        match $memo.get(&key) {
+            Some(value) => value,
+            None => {
+                let value = $body;
+                $memo.insert(key, value)
+            }
+        }
+    }};
+}
@@ -0,0 +1,29 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crate::Frame;
+
+/// Moves the cursor home and clears the terminal before every frame
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+/// Writes the frames as an asciicast v2 recording, which terminal players like asciinema replay.
+pub fn write<W: Write>(frames: &[Frame], frame_delay: Duration, out: &mut W) -> io::Result<()> {
+    let width = frames.iter().map(Frame::width).max().unwrap_or(0);
+    let height = frames.iter().map(Frame::height).max().unwrap_or(0);
+    writeln!(
+        out,
+        "{{\"version\": 2, \"width\": {}, \"height\": {}}}",
+        width, height
+    )?;
+    for (i, frame) in frames.iter().enumerate() {
+        let time = frame_delay.as_secs_f64() * i as f64;
+        let text = format!("{}{}", CLEAR, frame.to_string().replace('\n', "\r\n"));
+        writeln!(
+            out,
+            "[{:.3}, \"o\", {}]",
+            time,
+            serde_json::Value::from(text)
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use std::fmt::{self, Display, Formatter};
+
+/// A picture made of characters, e.g. a map of the simulated area.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<char>,
+}
+
+impl Frame {
+    pub fn from_fn<F: FnMut(usize, usize) -> char>(width: usize, height: usize, mut f: F) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Frame {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Uses every line of `text` as a row, shorter lines are padded with spaces.
+    pub fn from_text(text: &str) -> Self {
+        let lines: Vec<Vec<char>> = text.lines().map(|l| l.chars().collect()).collect();
+        let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+        Self::from_fn(width, lines.len(), |x, y| {
+            lines[y].get(x).copied().unwrap_or(' ')
+        })
+    }
+
+    /// Draws the points as `#` on a `.` background that covers the origin and all points.
+    pub fn from_points<I: IntoIterator<Item = (i64, i64)>>(points: I) -> Self {
+        let points: Vec<_> = points.into_iter().collect();
+        let min_x = points.iter().map(|p| p.0).min().unwrap_or(0).min(0);
+        let min_y = points.iter().map(|p| p.1).min().unwrap_or(0).min(0);
+        let max_x = points.iter().map(|p| p.0).max().unwrap_or(0).max(0);
+        let max_y = points.iter().map(|p| p.1).max().unwrap_or(0).max(0);
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        let mut frame = Self::from_fn(width, height, |_, _| '.');
+        for (x, y) in points {
+            frame.cells[(y - min_y) as usize * width + (x - min_x) as usize] = '#';
+        }
+        frame
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        if x < self.width && y < self.height {
+            Some(self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[char]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::Write;
+use std::time::Duration;
+
+use gif::{Encoder, Repeat};
+
+use crate::{ExportError, Frame};
+
+/// Largest side of the image that cells are scaled up to
+const TARGET_SIZE: usize = 800;
+
+/// Colors for characters other than background and `#`, assigned in order of appearance
+const PALETTE: [[u8; 3]; 12] = [
+    [230, 80, 70],
+    [80, 170, 230],
+    [240, 200, 60],
+    [110, 200, 110],
+    [200, 120, 220],
+    [240, 140, 60],
+    [70, 210, 200],
+    [160, 160, 160],
+    [150, 90, 50],
+    [240, 150, 180],
+    [120, 120, 240],
+    [180, 220, 90],
+];
+
+fn color_index(colors: &mut HashMap<char, u8>, c: char) -> u8 {
+    match c {
+        ' ' | '.' => 0,
+        '#' => 1,
+        c => {
+            let next = (colors.len() % PALETTE.len()) as u8 + 2;
+            *colors.entry(c).or_insert(next)
+        }
+    }
+}
+
+/// Writes the frames as a looping GIF, each character becomes a square of one color.
+pub fn write<W: Write>(
+    frames: &[Frame],
+    frame_delay: Duration,
+    out: &mut W,
+) -> Result<(), ExportError> {
+    let width = frames.iter().map(Frame::width).max().unwrap_or(0).max(1);
+    let height = frames.iter().map(Frame::height).max().unwrap_or(0).max(1);
+    let scale = (TARGET_SIZE / width.max(height)).max(1);
+    let (image_width, image_height) = (width * scale, height * scale);
+    let too_large = || ExportError::FrameTooLarge { width, height };
+    let gif_width = u16::try_from(image_width).map_err(|_| too_large())?;
+    let gif_height = u16::try_from(image_height).map_err(|_| too_large())?;
+
+    let mut palette = vec![0, 0, 0, 255, 255, 255];
+    palette.extend(PALETTE.iter().flatten());
+    let mut encoder = Encoder::new(out, gif_width, gif_height, &palette)?;
+    encoder.set_repeat(Repeat::Infinite)?;
+
+    let delay = (frame_delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+    let mut colors = HashMap::new();
+    for frame in frames {
+        let mut pixels = vec![0; image_width * image_height];
+        for (y, row) in frame.rows().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                let color = color_index(&mut colors, c);
+                for py in y * scale..(y + 1) * scale {
+                    let start = py * image_width + x * scale;
+                    pixels[start..start + scale].fill(color);
+                }
+            }
+        }
+        let mut gif_frame = gif::Frame::from_indexed_pixels(gif_width, gif_height, &pixels, None);
+        gif_frame.delay = delay;
+        encoder.write_frame(&gif_frame)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_frames_wider_than_a_gif() {
+        let frame = Frame::from_points(vec![(0, 0), (70_000, 1)]);
+        let result = write(&[frame], Duration::from_millis(100), &mut Vec::new());
+        assert!(matches!(
+            result,
+            Err(ExportError::FrameTooLarge {
+                width: 70_001,
+                height: 2
+            })
+        ));
+    }
+}
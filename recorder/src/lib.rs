@@ -0,0 +1,169 @@
+//! Collects frames from simulations and exports them as animations.
+//!
+//! Simulations push frames with [`Recorder::record`], which only builds a frame if the recorder
+//! wants it. Solving a puzzle therefore passes a [`Recorder::disabled`] recorder at no cost.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::Path;
+use std::time::Duration;
+
+mod asciicast;
+mod frame;
+mod gif_export;
+
+pub use frame::Frame;
+
+#[derive(Debug)]
+pub enum ExportError {
+    Io(io::Error),
+    Gif(gif::EncodingError),
+    /// The file extension does not name a supported format
+    UnknownFormat(String),
+    NoFrames,
+    /// A frame of the given width and height in cells exceeds the 65535 pixels a GIF allows
+    /// per side
+    FrameTooLarge {
+        width: usize,
+        height: usize,
+    },
+}
+
+impl From<io::Error> for ExportError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<gif::EncodingError> for ExportError {
+    fn from(e: gif::EncodingError) -> Self {
+        Self::Gif(e)
+    }
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "error writing animation: {}", e),
+            Self::Gif(e) => write!(f, "error encoding GIF: {}", e),
+            Self::UnknownFormat(path) => write!(
+                f,
+                "cannot tell the format of {:?} (expected a .cast or .gif file)",
+                path
+            ),
+            Self::NoFrames => write!(f, "no frames were recorded"),
+            Self::FrameTooLarge { width, height } => write!(
+                f,
+                "frame of {}x{} cells is too large for a GIF",
+                width, height
+            ),
+        }
+    }
+}
+
+impl Error for ExportError {}
+
+/// Animation formats the frames can be exported to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// asciicast v2, a terminal recording
+    Asciicast,
+    Gif,
+}
+
+impl Format {
+    /// Picks the format by the extension of `path`, i.e. `.cast` or `.gif`.
+    pub fn from_path(path: &Path) -> Result<Self, ExportError> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("cast") => Ok(Self::Asciicast),
+            Some("gif") => Ok(Self::Gif),
+            _ => Err(ExportError::UnknownFormat(path.display().to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Recorder {
+    enabled: bool,
+    /// Only every n-th frame offered to the recorder is kept
+    every: usize,
+    limit: usize,
+    offered: usize,
+    frames: Vec<Frame>,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Recorder {
+            enabled: true,
+            every: 1,
+            limit: usize::MAX,
+            offered: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    /// A recorder that ignores all frames, for running simulations without visualization.
+    pub fn disabled() -> Self {
+        Recorder {
+            enabled: false,
+            ..Self::new()
+        }
+    }
+
+    pub fn every(mut self, every: usize) -> Self {
+        self.every = every.max(1);
+        self
+    }
+
+    /// Stops recording once `limit` frames are kept.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.enabled && self.frames.len() < self.limit
+    }
+
+    /// Offers a frame to the recorder, `frame` is only called if the frame is kept.
+    pub fn record<F: FnOnce() -> Frame>(&mut self, frame: F) {
+        if !self.is_recording() {
+            return;
+        }
+        if self.offered.is_multiple_of(self.every) {
+            self.frames.push(frame());
+        }
+        self.offered += 1;
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Writes the frames as an animation, the format is picked by the extension of `path`.
+    pub fn export(&self, path: &Path, frame_delay: Duration) -> Result<(), ExportError> {
+        let format = Format::from_path(path)?;
+        if self.frames.is_empty() {
+            return Err(ExportError::NoFrames);
+        }
+        let mut out = BufWriter::new(File::create(path)?);
+        match format {
+            Format::Asciicast => asciicast::write(&self.frames, frame_delay, &mut out)?,
+            Format::Gif => gif_export::write(&self.frames, frame_delay, &mut out)?,
+        }
+        Ok(())
+    }
+}
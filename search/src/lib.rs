@@ -0,0 +1,215 @@
+//! Generic graph searches over implicitly defined state spaces.
+//!
+//! A puzzle describes its state space by implementing [`SearchProblem`] and then picks a strategy:
+//! [`bfs`] for the fewest steps, [`dijkstra`] for the lowest cost and [`astar`] for the lowest cost
+//! guided by the problem's heuristic.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Costs of moves, `Default` has to be the zero cost.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+    type Cost: Cost;
+
+    fn start(&self) -> Self::State;
+    /// States reachable from `state` in one move, together with the cost of the move
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, Self::Cost)>;
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// Lower bound on the cost from `state` to a goal, only used by [`astar`].
+    ///
+    /// It must never overestimate, otherwise A* may miss the cheapest path.
+    fn heuristic(&self, _state: &Self::State) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+/// Counters describing how much work a search did.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    /// States whose successors were generated
+    pub expanded: usize,
+    /// Successors generated, including ones that were already known
+    pub generated: usize,
+    /// Distinct states seen
+    pub discovered: usize,
+    pub max_frontier: usize,
+}
+
+/// A path from the start to a goal.
+#[derive(Clone, Debug)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// All states of the path, starting with the start state and ending with the goal
+    pub states: Vec<S>,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn goal(&self) -> &S {
+        self.states
+            .last()
+            .expect("a path contains at least the start")
+    }
+
+    pub fn steps(&self) -> usize {
+        self.states.len() - 1
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Outcome<S, C> {
+    /// `None` if no goal is reachable
+    pub path: Option<Path<S, C>>,
+    pub stats: SearchStats,
+}
+
+/// States seen so far, each with the index of its predecessor on the best known path.
+struct Nodes<S, C> {
+    index: HashMap<S, usize>,
+    states: Vec<S>,
+    parents: Vec<Option<usize>>,
+    costs: Vec<C>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Nodes<S, C> {
+    fn new() -> Self {
+        Nodes {
+            index: HashMap::new(),
+            states: Vec::new(),
+            parents: Vec::new(),
+            costs: Vec::new(),
+        }
+    }
+
+    /// Returns the index of `state` and whether it was seen for the first time.
+    fn intern(&mut self, state: S, parent: Option<usize>, cost: C) -> (usize, bool) {
+        match self.index.entry(state) {
+            Entry::Occupied(e) => (*e.get(), false),
+            Entry::Vacant(e) => {
+                let idx = self.states.len();
+                self.states.push(e.key().clone());
+                e.insert(idx);
+                self.parents.push(parent);
+                self.costs.push(cost);
+                (idx, true)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<S, C> {
+        let mut states = vec![self.states[goal].clone()];
+        let mut current = goal;
+        while let Some(parent) = self.parents[current] {
+            states.push(self.states[parent].clone());
+            current = parent;
+        }
+        states.reverse();
+        Path {
+            cost: self.costs[goal],
+            states,
+        }
+    }
+}
+
+/// Finds a path with the fewest moves, ignoring their costs.
+///
+/// The cost of the returned path is still the sum of its move costs.
+pub fn bfs<P: SearchProblem>(problem: &P) -> Outcome<P::State, P::Cost> {
+    let mut stats = SearchStats::default();
+    let mut nodes = Nodes::new();
+    let (start, _) = nodes.intern(problem.start(), None, P::Cost::default());
+    let mut queue = VecDeque::from(vec![start]);
+
+    while let Some(current) = queue.pop_front() {
+        if problem.is_goal(&nodes.states[current]) {
+            stats.discovered = nodes.states.len();
+            return Outcome {
+                path: Some(nodes.path(current)),
+                stats,
+            };
+        }
+        stats.expanded += 1;
+        for (next, cost) in problem.successors(&nodes.states[current]) {
+            stats.generated += 1;
+            let total = nodes.costs[current] + cost;
+            let (idx, new) = nodes.intern(next, Some(current), total);
+            if new {
+                queue.push_back(idx);
+            }
+        }
+        stats.max_frontier = stats.max_frontier.max(queue.len());
+    }
+    stats.discovered = nodes.states.len();
+    Outcome { path: None, stats }
+}
+
+/// Finds a cheapest path, exploring states in the order of their cost from the start.
+pub fn dijkstra<P: SearchProblem>(problem: &P) -> Outcome<P::State, P::Cost> {
+    best_first(problem, |_| P::Cost::default())
+}
+
+/// Finds a cheapest path, exploring states in the order of their cost plus heuristic.
+pub fn astar<P: SearchProblem>(problem: &P) -> Outcome<P::State, P::Cost> {
+    best_first(problem, |s| problem.heuristic(s))
+}
+
+fn best_first<P, H>(problem: &P, heuristic: H) -> Outcome<P::State, P::Cost>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> P::Cost,
+{
+    let mut stats = SearchStats::default();
+    let mut nodes = Nodes::new();
+    let mut closed = Vec::new();
+    let start_state = problem.start();
+    let estimate = heuristic(&start_state);
+    let (start, _) = nodes.intern(start_state, None, P::Cost::default());
+    closed.push(false);
+    // Entries are (estimated total cost, cost so far, state index), cheapest first
+    let mut frontier = BinaryHeap::new();
+    frontier.push(Reverse((estimate, P::Cost::default(), start)));
+
+    while let Some(Reverse((_, cost, current))) = frontier.pop() {
+        // Skip entries that were superseded by a cheaper path to the same state
+        if closed[current] || cost > nodes.costs[current] {
+            continue;
+        }
+        closed[current] = true;
+        if problem.is_goal(&nodes.states[current]) {
+            stats.discovered = nodes.states.len();
+            return Outcome {
+                path: Some(nodes.path(current)),
+                stats,
+            };
+        }
+        stats.expanded += 1;
+        for (next, step) in problem.successors(&nodes.states[current]) {
+            stats.generated += 1;
+            let total = cost + step;
+            let estimate = total + heuristic(&next);
+            let (idx, new) = nodes.intern(next, Some(current), total);
+            if new {
+                closed.push(false);
+            } else if total >= nodes.costs[idx] {
+                continue;
+            } else {
+                // A heuristic that is admissible but not consistent can require reopening states
+                nodes.costs[idx] = total;
+                nodes.parents[idx] = Some(current);
+                closed[idx] = false;
+            }
+            frontier.push(Reverse((estimate, total, idx)));
+        }
+        stats.max_frontier = stats.max_frontier.max(frontier.len());
+    }
+    stats.discovered = nodes.states.len();
+    Outcome { path: None, stats }
+}
@@ -0,0 +1,197 @@
+use std::fmt::{self, Display, Formatter};
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use input_parser::InputSource;
+use memo::MemoStats;
+use recorder::Recorder;
+
+/// The solution of a single day, split into parsing and the two puzzle parts.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+    /// Puzzle input compiled into the solution, usually via `include_str!`
+    const INPUT: &'static str;
+
+    /// Whether there is a second puzzle, which the last day does not have
+    const HAS_PART2: bool = true;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Named values describing the parsed input, such as the number of records or the bounds of
+    /// an area.
+    fn input_stats(_input: &Self::Input) -> Vec<(&'static str, i64)> {
+        Vec::new()
+    }
+
+    /// Replays the simulation behind the solution into `recorder`, if the day has one.
+    fn visualize(_input: &Self::Input, _recorder: &mut Recorder) -> Result<()> {
+        bail!("day {} has no visualization", Self::DAY)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Part {}", self.number())
+    }
+}
+
+/// Answer of one part together with the time it took to compute it.
+#[derive(Clone, Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: String,
+    pub time: Duration,
+    /// Statistics of the memo tables the part used, by table name
+    pub caches: Vec<(&'static str, MemoStats)>,
+}
+
+impl Display for PartRun {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.answer.contains('\n') {
+            write!(f, "{}:\n{}", self.part, self.answer)
+        } else {
+            write!(f, "{}: {}", self.part, self.answer)
+        }
+    }
+}
+
+/// Result of running a solution on one input.
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub day: u8,
+    pub input_bytes: usize,
+    pub input_lines: usize,
+    pub input_stats: Vec<(&'static str, i64)>,
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+/// Object-safe view of a [`Solution`], so solutions of all days can be kept in one list.
+pub trait Runner {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn input(&self) -> &'static str;
+    /// Parts the day has puzzles for
+    fn parts(&self) -> &'static [Part];
+    /// Fails without running anything if one of `parts` is not among [`Runner::parts`].
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run>;
+    fn visualize(&self, input: &str, recorder: &mut Recorder) -> Result<()>;
+}
+
+struct SolutionRunner<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> Runner for SolutionRunner<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn input(&self) -> &'static str {
+        S::INPUT
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        if S::HAS_PART2 {
+            &Part::BOTH
+        } else {
+            &[Part::One]
+        }
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run> {
+        if let Some(part) = parts.iter().find(|p| !self.parts().contains(p)) {
+            bail!("day {} has no part {}", S::DAY, part.number());
+        }
+        let start = Instant::now();
+        let parsed = S::parse(input).context("could not parse input")?;
+        let parse_time = start.elapsed();
+        let input_stats = S::input_stats(&parsed);
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                // Discard reports of tables dropped outside of the part
+                memo::take_reports();
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::part1(&parsed)?.to_string(),
+                    Part::Two => S::part2(&parsed)?.to_string(),
+                };
+                let time = start.elapsed();
+                Ok(PartRun {
+                    part,
+                    answer,
+                    time,
+                    caches: memo::take_reports(),
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Run {
+            day: S::DAY,
+            input_bytes: input.len(),
+            input_lines: input.lines().count(),
+            input_stats,
+            parse_time,
+            parts,
+        })
+    }
+
+    fn visualize(&self, input: &str, recorder: &mut Recorder) -> Result<()> {
+        let parsed = S::parse(input).context("could not parse input")?;
+        S::visualize(&parsed, recorder)
+    }
+}
+
+pub fn runner<S: Solution + 'static>() -> Box<dyn Runner> {
+    Box::new(SolutionRunner::<S>(PhantomData))
+}
+
+/// Entry point of the per-day binaries: solves both parts for the input given on the command line.
+pub fn main<S: Solution + 'static>() -> Result<()> {
+    let source = InputSource::from_args_or(InputSource::Embedded(S::INPUT));
+    let input = source
+        .read_to_string()
+        .with_context(|| format!("could not read {}", source))?;
+    let runner = runner::<S>();
+    let run = runner.run(&input, runner.parts())?;
+    println!("Day {}: {}", S::DAY, S::TITLE);
+    for part in run.parts {
+        println!("{}", part);
+    }
+    Ok(())
+}